@@ -1,4 +1,5 @@
-use chrono::NaiveDateTime;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Utc};
 
 use super::{SQLDataTypes, ToSQLData};
 
@@ -162,7 +163,15 @@ impl_fmt_data!(i32, Number);
 impl_fmt_data!(i64, Number);
 impl_fmt_data!(f32, Float);
 impl_fmt_data!(f64, Float);
-impl_fmt_data!(NaiveDateTime, Date);
+impl_fmt_data!(BigDecimal, Decimal);
+impl_fmt_data!(NaiveDate, Date);
+impl_fmt_data!(NaiveTime, Time);
+impl_fmt_data!(NaiveDateTime, Timestamp);
+impl_fmt_data!(DateTime<FixedOffset>, TimestampTz);
+impl_fmt_data!(DateTime<Utc>, TimestampTz);
+impl_fmt_data!(TimeDelta, Interval);
+impl_fmt_data!(bool, Bool);
+impl_fmt_data!(serde_json::Value, Json);
 
 macro_rules! impl_fmt_data_heap {
     ($data_type:ty, $enum_type:ident) => {
@@ -183,7 +192,15 @@ impl_fmt_data_heap!(Box<i32>, Number);
 impl_fmt_data_heap!(Box<i64>, Number);
 impl_fmt_data_heap!(Box<f32>, Float);
 impl_fmt_data_heap!(Box<f64>, Float);
-impl_fmt_data_heap!(Box<NaiveDateTime>, Date);
+impl_fmt_data_heap!(Box<BigDecimal>, Decimal);
+impl_fmt_data_heap!(Box<NaiveDate>, Date);
+impl_fmt_data_heap!(Box<NaiveTime>, Time);
+impl_fmt_data_heap!(Box<NaiveDateTime>, Timestamp);
+impl_fmt_data_heap!(Box<DateTime<FixedOffset>>, TimestampTz);
+impl_fmt_data_heap!(Box<DateTime<Utc>>, TimestampTz);
+impl_fmt_data_heap!(Box<TimeDelta>, Interval);
+impl_fmt_data_heap!(Box<bool>, Bool);
+impl_fmt_data_heap!(Box<serde_json::Value>, Json);
 
 macro_rules! impl_fmt_data_option {
     ($data_type:ty, $enum_type:ident) => {
@@ -206,7 +223,15 @@ impl_fmt_data_option!(Option<i32>, Number);
 impl_fmt_data_option!(Option<i64>, Number);
 impl_fmt_data_option!(Option<f32>, Float);
 impl_fmt_data_option!(Option<f64>, Float);
-impl_fmt_data_option!(Option<NaiveDateTime>, Date);
+impl_fmt_data_option!(Option<BigDecimal>, Decimal);
+impl_fmt_data_option!(Option<NaiveDate>, Date);
+impl_fmt_data_option!(Option<NaiveTime>, Time);
+impl_fmt_data_option!(Option<NaiveDateTime>, Timestamp);
+impl_fmt_data_option!(Option<DateTime<FixedOffset>>, TimestampTz);
+impl_fmt_data_option!(Option<DateTime<Utc>>, TimestampTz);
+impl_fmt_data_option!(Option<TimeDelta>, Interval);
+impl_fmt_data_option!(Option<bool>, Bool);
+impl_fmt_data_option!(Option<serde_json::Value>, Json);
 
 macro_rules! impl_fmt_data_option_heap {
     ($data_type:ty, $enum_type:ident) => {
@@ -232,4 +257,12 @@ impl_fmt_data_option_heap!(Option<Box<i32>>, Number);
 impl_fmt_data_option_heap!(Option<Box<i64>>, Number);
 impl_fmt_data_option_heap!(Option<Box<f32>>, Float);
 impl_fmt_data_option_heap!(Option<Box<f64>>, Float);
-impl_fmt_data_option_heap!(Option<Box<NaiveDateTime>>, Date);
+impl_fmt_data_option_heap!(Option<Box<BigDecimal>>, Decimal);
+impl_fmt_data_option_heap!(Option<Box<NaiveDate>>, Date);
+impl_fmt_data_option_heap!(Option<Box<NaiveTime>>, Time);
+impl_fmt_data_option_heap!(Option<Box<NaiveDateTime>>, Timestamp);
+impl_fmt_data_option_heap!(Option<Box<DateTime<FixedOffset>>>, TimestampTz);
+impl_fmt_data_option_heap!(Option<Box<DateTime<Utc>>>, TimestampTz);
+impl_fmt_data_option_heap!(Option<Box<TimeDelta>>, Interval);
+impl_fmt_data_option_heap!(Option<Box<bool>>, Bool);
+impl_fmt_data_option_heap!(Option<Box<serde_json::Value>>, Json);
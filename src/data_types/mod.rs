@@ -1,6 +1,7 @@
 use core::fmt;
 
-use chrono::NaiveDateTime;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta};
 
 pub mod implement;
 pub mod oracle;
@@ -11,25 +12,121 @@ pub enum SQLDataTypes {
     Varchar(String),
     Number(i64),
     Float(f64),
-    Date(NaiveDateTime),
+    Decimal(BigDecimal),
+    Date(NaiveDate),
+    Time(NaiveTime),
+    Timestamp(NaiveDateTime),
+    TimestampTz(DateTime<FixedOffset>),
+    Interval(SQLInterval),
+    Bool(bool),
+    Json(serde_json::Value),
     NULL,
 }
 
+/// An `INTERVAL` value. Oracle keeps year-to-month and day-to-second intervals as separate types,
+/// so they're kept separate here as well.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SQLInterval {
+    YearToMonth { years: i32, months: i32 },
+    DayToSecond(TimeDelta),
+}
+
 impl fmt::Display for SQLDataTypes {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             SQLDataTypes::Varchar(val) => write!(f, "{}", val),
             SQLDataTypes::Number(val) => write!(f, "{}", val),
             SQLDataTypes::Float(val) => write!(f, "{}", val),
+            SQLDataTypes::Decimal(val) => write!(f, "{}", val.to_plain_string()),
             SQLDataTypes::Date(val) => write!(f, "{}", val),
+            SQLDataTypes::Time(val) => write!(f, "{}", val),
+            SQLDataTypes::Timestamp(val) => write!(f, "{}", val),
+            SQLDataTypes::TimestampTz(val) => {
+                write!(f, "{}", val.format("%Y-%m-%d %H:%M:%S%.f %:z"))
+            }
+            SQLDataTypes::Interval(val) => write!(f, "{}", val),
+            SQLDataTypes::Bool(val) => write!(f, "{}", val),
+            SQLDataTypes::Json(val) => write!(f, "{}", val),
             SQLDataTypes::NULL => write!(f, ""),
         }
     }
 }
 
+impl fmt::Display for SQLInterval {
+    // Uses the same layout as Oracle's interval literals, `Y-M` and `D HH:MM:SS.FF`
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SQLInterval::YearToMonth { years, months } => write!(f, "{}-{}", years, months),
+            SQLInterval::DayToSecond(val) => {
+                let sign = if *val < TimeDelta::zero() { "-" } else { "" };
+                let val = val.abs();
+                let secs = val.num_seconds();
+                let nanos = val.subsec_nanos();
+                write!(
+                    f,
+                    "{sign}{} {:02}:{:02}:{:02}",
+                    secs / 86_400,
+                    (secs % 86_400) / 3_600,
+                    (secs % 3_600) / 60,
+                    secs % 60
+                )?;
+                if nanos > 0 {
+                    write!(f, ".{:09}", nanos)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl From<TimeDelta> for SQLInterval {
+    fn from(val: TimeDelta) -> Self {
+        SQLInterval::DayToSecond(val)
+    }
+}
+
+impl SQLInterval {
+    /// Parses the `Y-M` and `D HH:MM:SS.FF` layouts the intervals are displayed (and stored on SQLite) as.
+    pub(crate) fn parse(val: &str) -> Option<Self> {
+        let val = val.trim();
+        let (negative, val) = match val.strip_prefix('-') {
+            Some(val) => (true, val),
+            None => (false, val),
+        };
+        let sign = if negative { -1 } else { 1 };
+        match val.split_once(' ') {
+            Some((days, time)) => {
+                let mut parts = time.splitn(3, ':');
+                let (hours, minutes, seconds) = (parts.next()?, parts.next()?, parts.next()?);
+                let (seconds, fraction) = seconds.split_once('.').unwrap_or((seconds, ""));
+                let nanos = match fraction {
+                    "" => 0,
+                    fraction if fraction.len() <= 9 => format!("{fraction:0<9}").parse().ok()?,
+                    _ => return None,
+                };
+                let delta = TimeDelta::days(days.parse().ok()?)
+                    + TimeDelta::hours(hours.parse().ok()?)
+                    + TimeDelta::minutes(minutes.parse().ok()?)
+                    + TimeDelta::seconds(seconds.parse().ok()?)
+                    + TimeDelta::nanoseconds(nanos);
+                Some(SQLInterval::DayToSecond(delta * sign))
+            }
+            None => {
+                let (years, months) = val.split_once('-')?;
+                let years = years.parse::<i32>().ok()?;
+                let months = months.trim_start_matches('-').parse::<i32>().ok()?;
+                Some(SQLInterval::YearToMonth {
+                    years: years * sign,
+                    months: months * sign,
+                })
+            }
+        }
+    }
+}
+
 /// A trait that formats the input data to match [`SQLDataTypes`]
 ///
-/// Already implemented for `&[u8]`, `Vec<u8>`, `&str`,`String`, `i8`, `i16`, `i32`, `i64`, `f32`, `f64`, and [`chrono::NaiveDateTime`], as well as, their Option<> and Box<> variants
+/// Already implemented for `&[u8]`, `Vec<u8>`, `&str`,`String`, `i8`, `i16`, `i32`, `i64`, `f32`, `f64`, [`bigdecimal::BigDecimal`], [`chrono::NaiveDate`], [`chrono::NaiveTime`], [`chrono::NaiveDateTime`], [`chrono::DateTime`] (`FixedOffset` and `Utc`), [`chrono::TimeDelta`], `bool`, and [`serde_json::Value`], as well as, their Option<> and Box<> variants
 ///
 /// To implement a local enum:
 ///
@@ -71,3 +168,35 @@ impl fmt::Display for SQLDataTypes {
 pub trait ToSQLData {
     fn to_sql_fmt(&self) -> SQLDataTypes;
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeDelta;
+
+    use super::SQLInterval;
+
+    #[test]
+    fn interval_round_trips_through_display() {
+        let intervals = [
+            SQLInterval::YearToMonth {
+                years: 1,
+                months: 6,
+            },
+            SQLInterval::YearToMonth {
+                years: -2,
+                months: -3,
+            },
+            SQLInterval::DayToSecond(TimeDelta::seconds(90_061)),
+            SQLInterval::DayToSecond(-TimeDelta::milliseconds(3_723_500)),
+        ];
+        for interval in intervals {
+            assert_eq!(SQLInterval::parse(&interval.to_string()), Some(interval));
+        }
+    }
+
+    #[test]
+    fn interval_parse_rejects_other_text() {
+        assert_eq!(SQLInterval::parse("2024-01-05 10:00"), None);
+        assert_eq!(SQLInterval::parse("soon"), None);
+    }
+}
@@ -1,7 +1,10 @@
-use chrono::NaiveDateTime;
-use oracle::sql_type::{FromSql, OracleType, ToSql};
+use std::str::FromStr;
 
-use super::SQLDataTypes;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, FixedOffset, NaiveDateTime, TimeDelta};
+use oracle::sql_type::{FromSql, IntervalYM, OracleType, ToSql};
+
+use super::{SQLDataTypes, SQLInterval};
 
 impl FromSql for SQLDataTypes {
     fn from_sql(val: &oracle::SqlValue) -> oracle::Result<Self>
@@ -21,59 +24,82 @@ impl FromSql for SQLDataTypes {
             OracleType::Raw(_) => SQLDataTypes::Varchar(val.get::<String>()?),
             OracleType::BinaryFloat => SQLDataTypes::Float(val.get::<f64>()?),
             OracleType::BinaryDouble => SQLDataTypes::Float(val.get::<f64>()?),
-            OracleType::Number(_, _) => {
-                let buff = val.get::<String>()?;
-                if buff.contains(".") {
-                    SQLDataTypes::Float(val.get::<f64>()?)
-                } else {
-                    SQLDataTypes::Number(val.get::<i64>()?)
-                }
-            }
+            OracleType::Number(_, _) => number_from_sql(val)?,
             OracleType::Float(_) => SQLDataTypes::Float(val.get::<f64>()?),
-            OracleType::Date => SQLDataTypes::Date(val.get::<NaiveDateTime>()?),
-            OracleType::Timestamp(_) => SQLDataTypes::Date(val.get::<NaiveDateTime>()?),
-            OracleType::TimestampTZ(_) => SQLDataTypes::Date(val.get::<NaiveDateTime>()?),
-            OracleType::TimestampLTZ(_) => SQLDataTypes::Date(val.get::<NaiveDateTime>()?),
-            OracleType::IntervalDS(_, _) => SQLDataTypes::Varchar(val.get::<String>()?),
-            OracleType::IntervalYM(_) => SQLDataTypes::Varchar(val.get::<String>()?),
+            // Oracle's DATE carries a time as well, so it's returned as a timestamp to not lose it
+            OracleType::Date => SQLDataTypes::Timestamp(val.get::<NaiveDateTime>()?),
+            OracleType::Timestamp(_) => SQLDataTypes::Timestamp(val.get::<NaiveDateTime>()?),
+            OracleType::TimestampTZ(_) => {
+                SQLDataTypes::TimestampTz(val.get::<DateTime<FixedOffset>>()?)
+            }
+            OracleType::TimestampLTZ(_) => {
+                SQLDataTypes::TimestampTz(val.get::<DateTime<FixedOffset>>()?)
+            }
+            OracleType::IntervalDS(_, _) => {
+                SQLDataTypes::Interval(SQLInterval::DayToSecond(val.get::<TimeDelta>()?))
+            }
+            OracleType::IntervalYM(_) => {
+                let interval = val.get::<IntervalYM>()?;
+                SQLDataTypes::Interval(SQLInterval::YearToMonth {
+                    years: interval.years(),
+                    months: interval.months(),
+                })
+            }
             OracleType::CLOB => SQLDataTypes::Varchar(val.get::<String>()?),
             OracleType::NCLOB => SQLDataTypes::Varchar(val.get::<String>()?),
             OracleType::BLOB => SQLDataTypes::Varchar(val.get::<String>()?),
             OracleType::BFILE => SQLDataTypes::Varchar(val.get::<String>()?),
             OracleType::RefCursor => SQLDataTypes::Varchar(val.get::<String>()?),
-            OracleType::Boolean => SQLDataTypes::Varchar(val.get::<String>()?),
+            OracleType::Boolean => SQLDataTypes::Bool(val.get::<bool>()?),
             OracleType::Object(_) => SQLDataTypes::Varchar(val.get::<String>()?),
             OracleType::Long => SQLDataTypes::Varchar(val.get::<String>()?),
             OracleType::LongRaw => SQLDataTypes::Varchar(val.get::<String>()?),
-            OracleType::Json => SQLDataTypes::Varchar(val.get::<String>()?),
-            OracleType::Xml => SQLDataTypes::Varchar(val.get::<String>()?),
-            OracleType::Int64 => {
+            OracleType::Json => {
                 let buff = val.get::<String>()?;
-                if buff.contains(".") {
-                    SQLDataTypes::Float(val.get::<f64>()?)
-                } else {
-                    SQLDataTypes::Number(val.get::<i64>()?)
-                }
-            }
-            OracleType::UInt64 => {
-                let buff = val.get::<String>()?;
-                if buff.contains(".") {
-                    SQLDataTypes::Float(val.get::<f64>()?)
-                } else {
-                    SQLDataTypes::Number(val.get::<i64>()?)
+                match serde_json::from_str(&buff) {
+                    Ok(json) => SQLDataTypes::Json(json),
+                    Err(_) => SQLDataTypes::Varchar(buff),
                 }
             }
+            OracleType::Xml => SQLDataTypes::Varchar(val.get::<String>()?),
+            OracleType::Int64 => number_from_sql(val)?,
+            OracleType::UInt64 => number_from_sql(val)?,
         })
     }
 }
 
+// NUMBER values that don't fit into an i64 (decimals, NUMBER(38) IDs, etc..) are kept as BigDecimal
+// so they don't lose precision going through f64.
+fn number_from_sql(val: &oracle::SqlValue) -> oracle::Result<SQLDataTypes> {
+    let buff = val.get::<String>()?;
+    if let Ok(int) = buff.parse::<i64>() {
+        return Ok(SQLDataTypes::Number(int));
+    }
+    match BigDecimal::from_str(&buff) {
+        Ok(decimal) => Ok(SQLDataTypes::Decimal(decimal)),
+        Err(_) => Ok(SQLDataTypes::Float(val.get::<f64>()?)),
+    }
+}
+
 impl ToSql for SQLDataTypes {
     fn oratype(&self, conn: &oracle::Connection) -> oracle::Result<OracleType> {
         match self {
             SQLDataTypes::Varchar(val) => val.oratype(conn),
             SQLDataTypes::Number(val) => val.oratype(conn),
             SQLDataTypes::Float(val) => val.oratype(conn),
+            SQLDataTypes::Decimal(_) => Ok(OracleType::Number(0, 0)),
             SQLDataTypes::Date(val) => val.oratype(conn),
+            // Oracle doesn't have a TIME type, so it's stored as text
+            SQLDataTypes::Time(val) => val.to_string().oratype(conn),
+            SQLDataTypes::Timestamp(val) => val.oratype(conn),
+            SQLDataTypes::TimestampTz(val) => val.oratype(conn),
+            SQLDataTypes::Interval(SQLInterval::DayToSecond(val)) => val.oratype(conn),
+            SQLDataTypes::Interval(SQLInterval::YearToMonth { .. }) => {
+                Ok(OracleType::IntervalYM(9))
+            }
+            // Bound as 1/0 so it works with both BOOLEAN and the NUMBER(1) fallback on older versions
+            SQLDataTypes::Bool(val) => i64::from(*val).oratype(conn),
+            SQLDataTypes::Json(val) => val.to_string().oratype(conn),
             // Null match is practically worthless (real integration at batch_bind in insert).
             // Spent hours trying to find a clever way integrate NULL type with OracleType
             // but they structured it in a way that they need an Option<T> but it couldn't return None::<ToSql>
@@ -87,7 +113,17 @@ impl ToSql for SQLDataTypes {
             SQLDataTypes::Varchar(v) => val.set(v),
             SQLDataTypes::Number(v) => val.set(v),
             SQLDataTypes::Float(v) => val.set(v),
+            SQLDataTypes::Decimal(v) => val.set(&v.to_plain_string()),
             SQLDataTypes::Date(v) => val.set(v),
+            SQLDataTypes::Time(v) => val.set(&v.to_string()),
+            SQLDataTypes::Timestamp(v) => val.set(v),
+            SQLDataTypes::TimestampTz(v) => val.set(v),
+            SQLDataTypes::Interval(SQLInterval::DayToSecond(v)) => val.set(v),
+            SQLDataTypes::Interval(SQLInterval::YearToMonth { years, months }) => {
+                val.set(&IntervalYM::new(*years, *months)?)
+            }
+            SQLDataTypes::Bool(v) => val.set(&i64::from(*v)),
+            SQLDataTypes::Json(v) => val.set(&v.to_string()),
             SQLDataTypes::NULL => val.set_null(),
         }
     }
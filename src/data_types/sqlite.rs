@@ -1,6 +1,9 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime};
 use rusqlite::types::{ToSqlOutput, Value};
 
-use super::SQLDataTypes;
+use crate::{schema::sqlite::sqlite_declared_type, statements::create::CreateDataTypes};
+
+use super::{SQLDataTypes, SQLInterval};
 
 impl rusqlite::types::FromSql for SQLDataTypes {
     fn column_result(val: rusqlite::types::ValueRef<'_>) -> rusqlite::types::FromSqlResult<Self> {
@@ -20,13 +23,84 @@ impl rusqlite::types::FromSql for SQLDataTypes {
     }
 }
 
+/// The declared type of each column a statement returns. Needs to be read before the statement is queried.
+pub(crate) fn declared_types(stmt: &rusqlite::Statement) -> Vec<Option<CreateDataTypes>> {
+    stmt.columns()
+        .iter()
+        .map(|column| column.decl_type().map(sqlite_declared_type))
+        .collect()
+}
+
+/// Reads a cell the way [`FromSql`](rusqlite::types::FromSql) does, then turns the text SQLite stores
+/// dates, times, intervals and JSON as back into their types using the column's declared type.
+/// Text that doesn't parse is kept as a `Varchar`.
+pub(crate) fn sqlite_cell(
+    row: &rusqlite::Row,
+    idx: usize,
+    declared_types: &[Option<CreateDataTypes>],
+) -> rusqlite::Result<SQLDataTypes> {
+    let cell = row.get::<usize, SQLDataTypes>(idx)?;
+    let val = match (&cell, declared_types.get(idx)) {
+        (SQLDataTypes::Varchar(val), _) => val,
+        // JSON columns have NUMERIC affinity, so scalar numbers come back as numbers
+        (SQLDataTypes::Number(val), Some(Some(CreateDataTypes::JSON))) => {
+            return Ok(SQLDataTypes::Json((*val).into()));
+        }
+        (SQLDataTypes::Float(val), Some(Some(CreateDataTypes::JSON))) => {
+            return Ok(SQLDataTypes::Json((*val).into()));
+        }
+        _ => return Ok(cell),
+    };
+    let decoded = match declared_types.get(idx) {
+        Some(Some(CreateDataTypes::DATE)) => match NaiveDate::parse_from_str(val, "%Y-%m-%d") {
+            Ok(date) => Some(SQLDataTypes::Date(date)),
+            // Oracle DATEs copied over keep their time of day
+            Err(_) => parse_timestamp(val).map(SQLDataTypes::Timestamp),
+        },
+        Some(Some(CreateDataTypes::TIME)) => NaiveTime::parse_from_str(val, "%H:%M:%S%.f")
+            .ok()
+            .map(SQLDataTypes::Time),
+        Some(Some(CreateDataTypes::TIMESTAMP)) => parse_timestamp(val)
+            .map(SQLDataTypes::Timestamp)
+            .or_else(|| {
+                NaiveDate::parse_from_str(val, "%Y-%m-%d")
+                    .ok()
+                    .and_then(|date| date.and_hms_opt(0, 0, 0))
+                    .map(SQLDataTypes::Timestamp)
+            }),
+        Some(Some(CreateDataTypes::TIMESTAMPTZ)) => DateTime::parse_from_rfc3339(val)
+            .or_else(|_| DateTime::parse_from_str(val, "%Y-%m-%d %H:%M:%S%.f %:z"))
+            .ok()
+            .map(SQLDataTypes::TimestampTz),
+        Some(Some(CreateDataTypes::INTERVAL | CreateDataTypes::INTERVALYM)) => {
+            SQLInterval::parse(val).map(SQLDataTypes::Interval)
+        }
+        Some(Some(CreateDataTypes::JSON)) => serde_json::from_str(val).ok().map(SQLDataTypes::Json),
+        _ => None,
+    };
+    Ok(decoded.unwrap_or(cell))
+}
+
+fn parse_timestamp(val: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(val, "%Y-%m-%d %H:%M:%S%.f")
+        .or_else(|_| NaiveDateTime::parse_from_str(val, "%Y-%m-%dT%H:%M:%S%.f"))
+        .ok()
+}
+
 impl rusqlite::types::ToSql for SQLDataTypes {
     fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
         Ok(match self {
             SQLDataTypes::Varchar(val) => ToSqlOutput::Owned(Value::Text(val.to_string())),
             SQLDataTypes::Number(val) => ToSqlOutput::Owned(Value::Integer(*val)),
             SQLDataTypes::Float(val) => ToSqlOutput::Owned(Value::Real(*val)),
+            SQLDataTypes::Decimal(val) => ToSqlOutput::Owned(Value::Text(val.to_plain_string())),
             SQLDataTypes::Date(val) => ToSqlOutput::Owned(Value::Text(val.to_string())),
+            SQLDataTypes::Time(val) => ToSqlOutput::Owned(Value::Text(val.to_string())),
+            SQLDataTypes::Timestamp(val) => ToSqlOutput::Owned(Value::Text(val.to_string())),
+            SQLDataTypes::TimestampTz(val) => ToSqlOutput::Owned(Value::Text(val.to_rfc3339())),
+            SQLDataTypes::Interval(val) => ToSqlOutput::Owned(Value::Text(val.to_string())),
+            SQLDataTypes::Bool(val) => ToSqlOutput::Owned(Value::Integer(i64::from(*val))),
+            SQLDataTypes::Json(val) => ToSqlOutput::Owned(Value::Text(val.to_string())),
             SQLDataTypes::NULL => ToSqlOutput::Owned(Value::Null),
         })
     }
@@ -0,0 +1,321 @@
+use std::{
+    fmt::Write as _,
+    fs::File,
+    io::{BufWriter, Write},
+};
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, format::StrftimeItems};
+use serde_json::Value;
+
+use crate::{
+    Error, SQLImplementation,
+    data_types::SQLDataTypes,
+    schema::{oracle::oracle_query_columns, sqlite::sqlite_query_columns},
+    statements::{
+        create::CreateDataTypes,
+        insert::data_conversion::{date::date_conversion, datetime::datetime_conversion},
+        select::SelectProps,
+    },
+    transfer,
+};
+
+/// How [`export_json`](crate::statements::select::SelectBuilder::export_json) lays out the rows.
+#[derive(Debug, Clone)]
+pub enum JsonLayout {
+    /// An array of objects, one per row: `[{"city": "Austin", "revenue": 10}, ..]`
+    Records,
+    /// An object of arrays, one per column: `{"city": ["Austin", ..], "revenue": [10, ..]}`.
+    /// Unlike the other layouts, the whole result is held in memory before it's written.
+    Columns,
+    /// Newline delimited JSON, one object per line.
+    Ndjson,
+}
+
+/// What a `NULL` is written as.
+#[derive(Debug, Clone)]
+pub enum NullValue {
+    /// An empty field in a CSV and `null` in JSON.
+    Empty,
+    /// The same text in CSV and JSON, e.g. `NULL` or `N/A`.
+    Literal(String),
+}
+
+/// Options for exporting a select. Defaults to a comma delimited file with a header,
+/// ISO 8601 dates and timestamps, RFC 3339 timestamps with time zones, and empty NULLs.
+#[derive(Debug, Clone)]
+pub struct ExportOptions {
+    pub delimiter: u8,
+    /// Writes the column names as the first line of a CSV.
+    pub header: bool,
+    /// A [chrono pattern](https://docs.rs/chrono/latest/chrono/format/strftime/index.html) for `Date` values.
+    pub date_format: String,
+    /// A chrono pattern for `Timestamp` values.
+    pub timestamp_format: String,
+    /// A chrono pattern for `TimestampTz` values, which should keep the offset (`%:z`).
+    pub timestamptz_format: String,
+    pub null: NullValue,
+    /// Number of rows fetched at a time.
+    pub chunk_size: usize,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        ExportOptions {
+            delimiter: b',',
+            header: true,
+            date_format: "%Y-%m-%d".to_string(),
+            timestamp_format: "%Y-%m-%d %H:%M:%S".to_string(),
+            timestamptz_format: "%Y-%m-%dT%H:%M:%S%:z".to_string(),
+            null: NullValue::Empty,
+            chunk_size: 10_000,
+        }
+    }
+}
+
+impl ExportOptions {
+    /// Chrono panics while formatting a pattern it can't parse or can't fill from the value, e.g. `%H` for a `Date`,
+    /// so each pattern is parsed and tried on a value of its type before anything is exported.
+    fn check_formats(&self) -> Result<(), Error> {
+        let invalid = |format: &String| Error::InvalidFormat(format.to_string());
+        let mut buffer = String::new();
+
+        let items = StrftimeItems::new(&self.date_format)
+            .parse()
+            .map_err(|_| invalid(&self.date_format))?;
+        write!(
+            buffer,
+            "{}",
+            NaiveDate::default().format_with_items(items.iter())
+        )
+        .map_err(|_| invalid(&self.date_format))?;
+
+        let items = StrftimeItems::new(&self.timestamp_format)
+            .parse()
+            .map_err(|_| invalid(&self.timestamp_format))?;
+        write!(
+            buffer,
+            "{}",
+            NaiveDateTime::default().format_with_items(items.iter())
+        )
+        .map_err(|_| invalid(&self.timestamp_format))?;
+
+        let items = StrftimeItems::new(&self.timestamptz_format)
+            .parse()
+            .map_err(|_| invalid(&self.timestamptz_format))?;
+        let timestamptz = DateTime::UNIX_EPOCH.fixed_offset();
+        write!(buffer, "{}", timestamptz.format_with_items(items.iter()))
+            .map_err(|_| invalid(&self.timestamptz_format))?;
+        Ok(())
+    }
+}
+
+pub(crate) fn export_csv(
+    select_props: SelectProps,
+    path: &str,
+    options: ExportOptions,
+) -> Result<usize, Error> {
+    options.check_formats()?;
+    let (query, columns) = export_query(&select_props)?;
+    let mut writer = csv::WriterBuilder::new()
+        .delimiter(options.delimiter)
+        .from_path(path)?;
+    if options.header {
+        writer.write_record(columns.iter().map(|(name, _)| name))?;
+    }
+
+    let mut count = 0;
+    stream_rows(&select_props, &query, &columns, &options, |chunk| {
+        for row in chunk {
+            let record = row
+                .iter()
+                .map(|cell| fmt_cell(cell, &options))
+                .collect::<Result<Vec<String>, Error>>()?;
+            writer.write_record(record)?;
+        }
+        count += chunk.len();
+        Ok(())
+    })?;
+    writer.flush()?;
+    Ok(count)
+}
+
+pub(crate) fn export_json(
+    select_props: SelectProps,
+    path: &str,
+    layout: JsonLayout,
+    options: ExportOptions,
+) -> Result<usize, Error> {
+    options.check_formats()?;
+    let (query, columns) = export_query(&select_props)?;
+    // Objects are written by hand so the keys keep the select's column order
+    let keys = columns
+        .iter()
+        .map(|(name, _)| Value::String(name.to_string()).to_string())
+        .collect::<Vec<String>>();
+    let mut writer = BufWriter::new(File::create(path)?);
+
+    let mut count = 0;
+    let mut values: Vec<Vec<Value>> = vec![Vec::new(); keys.len()];
+    if let JsonLayout::Records = layout {
+        writer.write_all(b"[")?;
+    }
+    stream_rows(&select_props, &query, &columns, &options, |chunk| {
+        for row in chunk {
+            match layout {
+                JsonLayout::Records | JsonLayout::Ndjson => {
+                    if matches!(layout, JsonLayout::Records) && count > 0 {
+                        writer.write_all(b",")?;
+                    }
+                    let record = keys
+                        .iter()
+                        .zip(row)
+                        .map(|(key, cell)| Ok(format!("{}:{}", key, json_value(cell, &options)?)))
+                        .collect::<Result<Vec<String>, Error>>()?;
+                    write!(writer, "{{{}}}", record.join(","))?;
+                    if let JsonLayout::Ndjson = layout {
+                        writer.write_all(b"\n")?;
+                    }
+                }
+                JsonLayout::Columns => {
+                    for (idx, cell) in row.iter().enumerate() {
+                        values[idx].push(json_value(cell, &options)?);
+                    }
+                }
+            }
+            count += 1;
+        }
+        Ok(())
+    })?;
+    match layout {
+        JsonLayout::Records => writer.write_all(b"]")?,
+        JsonLayout::Columns => {
+            let object = keys
+                .iter()
+                .zip(values)
+                .map(|(key, values)| format!("{}:{}", key, Value::Array(values)))
+                .collect::<Vec<String>>();
+            write!(writer, "{{{}}}", object.join(","))?;
+        }
+        JsonLayout::Ndjson => (),
+    }
+    writer.flush()?;
+    Ok(count)
+}
+
+fn export_query(
+    select_props: &SelectProps,
+) -> Result<(String, Vec<(String, CreateDataTypes)>), Error> {
+    let query = select_props.to_sql()?;
+    let columns = match &select_props.connect {
+        SQLImplementation::Oracle(connect) => oracle_query_columns(connect, &query)?,
+        SQLImplementation::SQLite(connect) => sqlite_query_columns(connect, &query)?,
+    };
+    Ok((query, columns))
+}
+
+fn stream_rows(
+    select_props: &SelectProps,
+    query: &str,
+    columns: &[(String, CreateDataTypes)],
+    options: &ExportOptions,
+    mut on_chunk: impl FnMut(&[Vec<SQLDataTypes>]) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let chunk_size = options.chunk_size.max(1);
+    let typed_chunk = |mut chunk: Vec<Vec<SQLDataTypes>>| {
+        chunk.iter_mut().for_each(|row| {
+            row.iter_mut()
+                .zip(columns)
+                .for_each(|(cell, (_, data_type))| parse_temporal(cell, data_type))
+        });
+        on_chunk(&chunk)
+    };
+    match &select_props.connect {
+        SQLImplementation::Oracle(connect) => {
+            transfer::oracle::read_chunks(connect, query, columns.len(), chunk_size, typed_chunk)
+        }
+        SQLImplementation::SQLite(connect) => {
+            transfer::sqlite::read_chunks(connect, query, columns.len(), chunk_size, typed_chunk)
+        }
+    }
+}
+
+// SQLite keeps dates as text, so text in a DATE or TIMESTAMP column is parsed to pick up the date patterns
+fn parse_temporal(cell: &mut SQLDataTypes, data_type: &CreateDataTypes) {
+    let SQLDataTypes::Varchar(text) = cell else {
+        return;
+    };
+    let parsed = match data_type {
+        CreateDataTypes::DATE => date_conversion(&mut text.to_string()).map(SQLDataTypes::Date),
+        CreateDataTypes::TIMESTAMP => {
+            datetime_conversion(&mut text.to_string()).map(SQLDataTypes::Timestamp)
+        }
+        _ => return,
+    };
+    if let Ok(parsed) = parsed {
+        *cell = parsed;
+    }
+}
+
+fn fmt_cell(cell: &SQLDataTypes, options: &ExportOptions) -> Result<String, Error> {
+    // Writing into a String only fails when chrono can't fill the pattern
+    let mut buffer = String::new();
+    let res = match cell {
+        SQLDataTypes::Date(val) => write!(buffer, "{}", val.format(&options.date_format))
+            .map_err(|_| Error::InvalidFormat(options.date_format.to_string())),
+        SQLDataTypes::Timestamp(val) => write!(buffer, "{}", val.format(&options.timestamp_format))
+            .map_err(|_| Error::InvalidFormat(options.timestamp_format.to_string())),
+        SQLDataTypes::TimestampTz(val) => {
+            write!(buffer, "{}", val.format(&options.timestamptz_format))
+                .map_err(|_| Error::InvalidFormat(options.timestamptz_format.to_string()))
+        }
+        SQLDataTypes::Decimal(val) => return Ok(val.to_plain_string()),
+        SQLDataTypes::NULL => match &options.null {
+            NullValue::Empty => return Ok(String::new()),
+            NullValue::Literal(literal) => return Ok(literal.to_string()),
+        },
+        cell => return Ok(cell.to_string()),
+    };
+    res.map(|_| buffer)
+}
+
+// Decimals are written as strings so they keep their exact value
+fn json_value(cell: &SQLDataTypes, options: &ExportOptions) -> Result<Value, Error> {
+    Ok(match cell {
+        SQLDataTypes::Number(val) => Value::from(*val),
+        SQLDataTypes::Float(val) => serde_json::Number::from_f64(*val)
+            .map(Value::Number)
+            .unwrap_or(Value::Null),
+        SQLDataTypes::Bool(val) => Value::Bool(*val),
+        SQLDataTypes::Json(val) => val.to_owned(),
+        SQLDataTypes::NULL => match &options.null {
+            NullValue::Empty => Value::Null,
+            NullValue::Literal(literal) => Value::String(literal.to_string()),
+        },
+        cell => Value::String(fmt_cell(cell, options)?),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExportOptions;
+
+    #[test]
+    fn patterns_that_would_panic_are_rejected() {
+        assert!(ExportOptions::default().check_formats().is_ok());
+        let time_in_date = ExportOptions {
+            date_format: "%Y-%m-%d %H".to_string(),
+            ..Default::default()
+        };
+        assert!(time_in_date.check_formats().is_err());
+        let unknown = ExportOptions {
+            timestamp_format: "%Q".to_string(),
+            ..Default::default()
+        };
+        assert!(unknown.check_formats().is_err());
+        let offset_in_timestamp = ExportOptions {
+            timestamp_format: "%Y %:z".to_string(),
+            ..Default::default()
+        };
+        assert!(offset_in_timestamp.check_formats().is_err());
+    }
+}
@@ -0,0 +1,339 @@
+use std::{fs::File, mem::discriminant};
+
+use encoding_rs::Encoding;
+use encoding_rs_io::{DecodeReaderBytes, DecodeReaderBytesBuilder};
+use itertools::Itertools;
+
+use crate::{
+    Error, SQLImplementation,
+    data_types::{SQLDataTypes, SQLInterval, ToSQLData},
+    sql_implementations::{OracleConnect, SQLiteConnect},
+    statements::{
+        create::{CreateColumns, CreateDataTypes, ModifyCreateTable},
+        insert::{
+            ErrorPolicy, InsertBuilder, InsertProps, ParseOptions,
+            data_conversion::format_grid,
+            sql_implementations::oracle::validation::{decimal_digits, get_col_indexes},
+        },
+    },
+    utils::remove_invalid_chars,
+};
+
+/// Options for [`import_csv`](SQLImplementation::import_csv).
+/// Defaults to a comma delimited, double quoted, UTF-8 file with a header, read 10,000 rows at a time.
+#[derive(Debug, Clone)]
+pub struct CsvOptions {
+    pub delimiter: u8,
+    pub quote: u8,
+    /// Without a header, the columns are taken in order from the table, so it has to exist.
+    pub has_header: bool,
+    /// Any label the [Encoding Standard](https://encoding.spec.whatwg.org/#names-and-labels) knows, e.g. `utf-8`, `windows-1252` or `latin1`.
+    pub encoding: String,
+    /// Number of rows parsed and inserted at a time.
+    pub chunk_size: usize,
+}
+
+/// What [`import_csv`](SQLImplementation::import_csv) inserted and which lines it skipped.
+#[derive(Debug, Clone)]
+pub struct CsvReport {
+    pub rows_inserted: usize,
+    pub rejected: Vec<RejectedLine>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RejectedLine {
+    /// The line the record starts on, counting the header.
+    pub line: u64,
+    pub reason: String,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        CsvOptions {
+            delimiter: b',',
+            quote: b'"',
+            has_header: true,
+            encoding: "utf-8".to_string(),
+            chunk_size: 10_000,
+        }
+    }
+}
+
+impl SQLImplementation {
+    /// Streams a CSV file into a table in chunks, so the file never has to fit in memory.
+    ///
+    /// Each chunk's fields are parsed the way [`format_grid_strings`](InsertBuilder::format_grid_strings) parses them,
+    /// except in text columns, which keep the field as it's written.
+    /// If the table doesn't exist, the file is read through once first and the table is created
+    /// from the types and sizes of every record, so later chunks fit the columns the same as the first one.
+    /// Records that can't be parsed, don't have the same number of fields as the header,
+    /// or that the database rejects are skipped and listed in the [`CsvReport`].
+    /// ```no_run
+    /// let conn = SQLiteConnect::from_path("path/to/file.db");
+    /// let options = CsvOptions { delimiter: b';', encoding: "windows-1252".to_string(), ..Default::default() };
+    /// let report = conn.import_csv("sales.csv", "regional_sales", options)?;
+    /// for rejected in report.rejected {
+    ///     println!("line {}: {}", rejected.line, rejected.reason);
+    /// }
+    /// ```
+    pub fn import_csv(
+        &self,
+        path: &str,
+        table: &str,
+        options: CsvOptions,
+    ) -> Result<CsvReport, Error> {
+        let mut reader = csv_reader(path, &options)?;
+        let header = match options.has_header {
+            true => Some(
+                reader
+                    .headers()?
+                    .iter()
+                    .map(|head| head.to_string())
+                    .collect::<Vec<String>>(),
+            ),
+            false => None,
+        };
+        let schema = match (self.describe_table(table), &header) {
+            (Ok(schema), _) => schema,
+            (Err(Error::TableDoesNotExist), Some(header)) => {
+                let columns = scan_csv(path, &options, header)?;
+                self.clone()
+                    .create_initialization()
+                    .table(table, columns)
+                    .build()?;
+                self.describe_table(table)?
+            }
+            (Err(err), _) => return Err(err),
+        };
+        let width = match &header {
+            Some(header) => header.len(),
+            None => schema.columns.len(),
+        };
+        let text_columns = (0..width)
+            .map(|idx| {
+                let column = match &header {
+                    Some(header) => {
+                        let head = remove_invalid_chars(&header[idx]);
+                        schema
+                            .columns
+                            .iter()
+                            .find(|column| column.name.eq_ignore_ascii_case(&head))
+                    }
+                    None => schema.columns.get(idx),
+                };
+                matches!(
+                    column.map(|column| &column.data_type),
+                    Some(CreateDataTypes::VARCHAR(_) | CreateDataTypes::CLOB)
+                )
+            })
+            .collect::<Vec<bool>>();
+
+        let chunk_size = options.chunk_size.max(1);
+        let mut report = CsvReport {
+            rows_inserted: 0,
+            rejected: Vec::new(),
+        };
+        let mut chunk: Vec<Vec<String>> = Vec::with_capacity(chunk_size);
+        let mut lines: Vec<u64> = Vec::with_capacity(chunk_size);
+        for record in reader.records() {
+            match record {
+                Ok(record) if record.len() == width => {
+                    lines.push(record.position().map(|pos| pos.line()).unwrap_or(0));
+                    chunk.push(record.iter().map(|field| field.to_string()).collect());
+                }
+                Ok(record) => report.rejected.push(RejectedLine {
+                    line: record.position().map(|pos| pos.line()).unwrap_or(0),
+                    reason: format!("expected {} fields, found {}", width, record.len()),
+                }),
+                Err(err) => report.rejected.push(RejectedLine {
+                    line: err.position().map(|pos| pos.line()).unwrap_or(0),
+                    reason: err.to_string(),
+                }),
+            }
+            if chunk.len() == chunk_size {
+                self.insert_csv_chunk(table, &header, &text_columns, chunk, &lines, &mut report)?;
+                chunk = Vec::with_capacity(chunk_size);
+                lines.clear();
+            }
+        }
+        if !chunk.is_empty() {
+            self.insert_csv_chunk(table, &header, &text_columns, chunk, &lines, &mut report)?;
+        }
+        report.rejected.sort_by_key(|rejected| rejected.line);
+
+        Ok(report)
+    }
+
+    fn insert_csv_chunk(
+        &self,
+        table: &str,
+        header: &Option<Vec<String>>,
+        text_columns: &[bool],
+        rows: Vec<Vec<String>>,
+        lines: &[u64],
+        report: &mut CsvReport,
+    ) -> Result<(), Error> {
+        let mut grid = rows
+            .iter()
+            .map(|row| row.iter().map(|field| field.to_sql_fmt()).collect())
+            .collect::<Vec<Vec<SQLDataTypes>>>();
+        format_grid(&mut grid, &ParseOptions::default());
+        // Text columns keep the field as written, so "007" or "Yes" isn't stored as 7 or true
+        for (row, fields) in grid.iter_mut().zip(rows) {
+            for ((cell, field), is_text) in row.iter_mut().zip(fields).zip(text_columns) {
+                if *is_text && *cell != SQLDataTypes::NULL {
+                    *cell = SQLDataTypes::Varchar(field);
+                }
+            }
+        }
+        let insert_props: InsertProps = match header {
+            Some(header) => {
+                grid.insert(0, header.iter().map(|head| head.to_sql_fmt()).collect());
+                self.clone().insert_initialization(table, grid)?
+            }
+            None => self.clone().insert_rows_initialization(table, grid)?,
+        };
+        let inserted = insert_props.on_error(ErrorPolicy::Collect).build()?;
+        report.rows_inserted += inserted.inserted;
+        report
+            .rejected
+            .extend(inserted.failed.into_iter().map(|failed| RejectedLine {
+                line: lines.get(failed.index).copied().unwrap_or(0),
+                reason: failed.message,
+            }));
+        Ok(())
+    }
+}
+
+fn csv_reader(
+    path: &str,
+    options: &CsvOptions,
+) -> Result<csv::Reader<DecodeReaderBytes<File, Vec<u8>>>, Error> {
+    let encoding = Encoding::for_label(options.encoding.as_bytes())
+        .ok_or(Error::UnknownEncoding(options.encoding.to_string()))?;
+    let file = DecodeReaderBytesBuilder::new()
+        .encoding(Some(encoding))
+        .build(File::open(path)?);
+    Ok(csv::ReaderBuilder::new()
+        .delimiter(options.delimiter)
+        .quote(options.quote)
+        .has_headers(options.has_header)
+        .flexible(true)
+        .from_reader(file))
+}
+
+/// Reads the whole file once to find the columns of a new table.
+/// Only the cells that decide a column's type and size are kept between chunks,
+/// so the columns come out the same as if every record had been inferred at once.
+fn scan_csv(
+    path: &str,
+    options: &CsvOptions,
+    header: &[String],
+) -> Result<Vec<CreateColumns>, Error> {
+    let mut reader = csv_reader(path, options)?;
+    let width = header.len();
+    let chunk_size = options.chunk_size.max(1);
+    // Text columns are inserted as written, so they're sized by the longest field
+    let mut field_sizes = vec![0; width];
+    let mut sample: Vec<Vec<SQLDataTypes>> = Vec::new();
+    let mut chunk: Vec<Vec<SQLDataTypes>> = Vec::with_capacity(chunk_size);
+    let mut records = reader.records().filter_map(|record| record.ok()).peekable();
+    while records.peek().is_some() {
+        for record in records
+            .by_ref()
+            .filter(|record| record.len() == width)
+            .take(chunk_size)
+        {
+            for (size, field) in field_sizes.iter_mut().zip(record.iter()) {
+                *size = field.len().max(*size);
+            }
+            chunk.push(record.iter().map(|field| field.to_sql_fmt()).collect());
+        }
+        format_grid(&mut chunk, &ParseOptions::default());
+        sample.append(&mut chunk);
+        sample = sample_columns(sample, width);
+    }
+
+    let header = header
+        .iter()
+        .map(remove_invalid_chars)
+        .collect::<Vec<String>>();
+    let columns = get_col_indexes(&sample)?
+        .to_create_columns(&header)
+        .into_iter()
+        .zip(field_sizes)
+        .map(|(mut column, field_size)| {
+            if let CreateDataTypes::VARCHAR(size) = column.data_type {
+                column.data_type = CreateDataTypes::VARCHAR(size.max(field_size).max(1));
+            }
+            column
+        })
+        .collect();
+    Ok(columns)
+}
+
+/// Keeps the first cell of each type in every column, plus the longest cell
+/// and the ones with the most integer digits and decimal places.
+fn sample_columns(grid: Vec<Vec<SQLDataTypes>>, width: usize) -> Vec<Vec<SQLDataTypes>> {
+    let columns = (0..width)
+        .map(|x_idx| {
+            let cells = grid
+                .iter()
+                .filter_map(|row| row.get(x_idx))
+                .filter(|cell| **cell != SQLDataTypes::NULL);
+            let mut kept = cells
+                .clone()
+                .unique_by(|cell| {
+                    (
+                        discriminant(*cell),
+                        matches!(
+                            cell,
+                            SQLDataTypes::Interval(SQLInterval::YearToMonth { .. })
+                        ),
+                    )
+                })
+                .collect::<Vec<&SQLDataTypes>>();
+            kept.extend(cells.clone().max_by_key(|cell| cell.to_string().len()));
+            kept.extend(
+                cells
+                    .clone()
+                    .max_by_key(|cell| decimal_digits(cell).map(|(int, _)| int)),
+            );
+            kept.extend(cells.max_by_key(|cell| decimal_digits(cell).map(|(_, scale)| scale)));
+            kept.into_iter().cloned().collect::<Vec<SQLDataTypes>>()
+        })
+        .collect::<Vec<Vec<SQLDataTypes>>>();
+    let height = columns.iter().map(|column| column.len()).max().unwrap_or(0);
+    (0..height)
+        .map(|y_idx| {
+            columns
+                .iter()
+                .map(|column| column.get(y_idx).cloned().unwrap_or(SQLDataTypes::NULL))
+                .collect()
+        })
+        .collect()
+}
+
+impl OracleConnect {
+    /// See [`SQLImplementation::import_csv`].
+    pub fn import_csv(
+        &self,
+        path: &str,
+        table: &str,
+        options: CsvOptions,
+    ) -> Result<CsvReport, Error> {
+        SQLImplementation::Oracle(self.clone()).import_csv(path, table, options)
+    }
+}
+
+impl SQLiteConnect {
+    /// See [`SQLImplementation::import_csv`].
+    pub fn import_csv(
+        &self,
+        path: &str,
+        table: &str,
+        options: CsvOptions,
+    ) -> Result<CsvReport, Error> {
+        SQLImplementation::SQLite(self.clone()).import_csv(path, table, options)
+    }
+}
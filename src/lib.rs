@@ -3,14 +3,25 @@
 use data_types::ToSQLData;
 use sql_implementations::{OracleConnect, SQLiteConnect};
 use statements::{
-    alter::AlterProps, create::CreateProps, delete::DeleteProps, insert::InsertProps,
-    select::SelectProps, select::Column, update::UpdateProps
+    alter::AlterProps,
+    create::CreateProps,
+    delete::DeleteProps,
+    drop::DropProps,
+    insert::{InsertFromProps, InsertIterProps, InsertProps},
+    select::Column,
+    select::SelectProps,
+    truncate::TruncateProps,
+    update::UpdateProps,
 };
 
 pub mod data_types;
+pub mod export;
+pub mod import;
 pub mod query_conjunctions;
+pub mod schema;
 pub mod sql_implementations;
 pub mod statements;
+pub mod transfer;
 pub mod utils;
 
 #[derive(thiserror::Error, Debug)]
@@ -21,6 +32,12 @@ pub enum Error {
     #[error(transparent)]
     SQLiteError(#[from] rusqlite::Error),
 
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+
+    #[error(transparent)]
+    CsvError(#[from] csv::Error),
+
     #[error("Data does not exists")]
     NoData,
 
@@ -47,6 +64,39 @@ pub enum Error {
 
     #[error("Update using set_query method is not valid")]
     UpdateSetQuery,
+
+    #[error("Index needs a table and at least one column, use the `on` method")]
+    NoIndexColumns,
+
+    #[error("Column {0} doesn't exist")]
+    ColumnDoesNotExist(String),
+
+    #[error("Expected {0} columns but got {1}")]
+    ColumnCountMismatch(usize, usize),
+
+    #[error("Oracle can't return values from an upsert since MERGE doesn't support RETURNING")]
+    MergeReturning,
+
+    #[error("An upsert needs at least one key column to match on")]
+    NoConflictKeys,
+
+    #[error("Column {0} would need {1} bytes, over Oracle's 4000 byte VARCHAR2 limit")]
+    VarcharLimit(String, usize),
+
+    #[error("Unknown encoding {0}")]
+    UnknownEncoding(String),
+
+    #[error("Invalid chrono pattern {0}")]
+    InvalidFormat(String),
+
+    #[error("Row {0} failed to insert: {1}")]
+    RowInsertError(usize, String),
+
+    #[error("An insert thread panicked")]
+    InsertThreadPanicked,
+
+    #[error("{} values couldn't be converted to their column's type", .0.len())]
+    CoercionError(Vec<statements::insert::ValidationIssue>),
 }
 
 /// Trait used for the SQL Database types found in [`SQLVariation`] to implement basic SQL queries.
@@ -124,15 +174,75 @@ pub trait QueryBuilder {
     /// ```
     fn insert<T: ToSQLData>(&self, table: &str, data: Vec<Vec<T>>) -> Result<InsertProps, Error>;
 
+    /// Creates a new [`InsertProps`] from rows without a header.
+    ///
+    /// The columns are taken in order from the table's schema, so the table has to exist.
+    /// Use [`columns`](crate::statements::insert::InsertBuilder::columns) to insert into a subset or a different order of the table's columns.
+    /// ```no_run
+    /// let conn = OracleConnect::new(connection_string, username, password)?;
+    /// let rows = vec![
+    ///     vec!["1001".to_sql_fmt(), "Austin".to_sql_fmt()],
+    ///     vec!["1002".to_sql_fmt(), "Dallas".to_sql_fmt()],
+    /// ];
+    /// conn.insert_rows("regional_sales", rows)?.build()?;
+    /// ```
+    fn insert_rows<T: ToSQLData>(
+        &self,
+        table: &str,
+        rows: Vec<Vec<T>>,
+    ) -> Result<InsertProps, Error>;
+
+    /// Creates a new [`InsertFromProps`] which inserts the rows of a select query with `INSERT INTO ... SELECT`,
+    /// so the rows never leave the database.
+    /// The target columns line up with the select's columns, leave them empty to insert into every column.
+    /// Both queries need to be on the same database.
+    /// ```no_run
+    /// let conn = OracleConnect::new(connection_string, username, password)?;
+    /// let select = conn
+    ///     .select("regional_sales", vec![Column::ALL("regional_sales".to_string())])
+    ///     .where_in(&region, WhereArg::Values(vec!["West".to_sql_fmt()]));
+    /// conn.insert_from("west_sales", &[], select)
+    ///     .create_table()
+    ///     .build()?;
+    /// ```
+    fn insert_from(&self, table: &str, columns: &[&str], select: SelectProps) -> InsertFromProps;
+
+    /// Creates a new [`InsertIterProps`] which streams rows from an iterator into a table in batches,
+    /// so the whole dataset never has to sit in memory.
+    ///
+    /// Each batch is bound with Oracle array DML or a SQLite prepared statement and committed on its own.
+    /// A [`Receiver`](std::sync::mpsc::Receiver) is an iterator too, so rows can come from a producer on another thread.
+    /// A batch is sent once it's full or the channel closes.
+    /// ```no_run
+    /// let conn = SQLiteConnect::from_path("path/to/file.db");
+    /// let (sender, receiver) = std::sync::mpsc::channel();
+    /// let producer = std::thread::spawn(move || {
+    ///     for id in 0..1_000_000 {
+    ///         sender.send(vec![id.to_sql_fmt(), format!("item {id}").to_sql_fmt()]).unwrap();
+    ///     }
+    /// });
+    /// let inserted = conn
+    ///     .insert_iter("items", &["id", "name"], receiver)
+    ///     .batch_size(5_000)
+    ///     .create_table()
+    ///     .build()?;
+    /// producer.join().unwrap();
+    /// ```
+    fn insert_iter<'a, T, I>(&self, table: &str, header: &[&str], rows: I) -> InsertIterProps<'a>
+    where
+        T: ToSQLData + 'a,
+        I: IntoIterator<Item = Vec<T>>,
+        I::IntoIter: 'a;
+
     /// Creates a new [`CreateProps`] to start building a create query.
     ///
     /// Creates a table using a vector of the `CreateColumns` struct and the `CreateDataTypes` to apply the correct types to the new columns.
     /// ```no_run
     /// let conn = OracleConnect::new(connection_string, username, password)?;
     /// let columns = vec![
-    ///     CreateColumns{ name: "Column_A".to_string(), data_type: CreateDataTypes::VARCHAR(20 as usize) },
-    ///     CreateColumns{ name: "Column_B".to_string(), data_type: CreateDataTypes::NUMBER },
-    ///     CreateColumns{ name: "Column_C".to_string(), data_type: CreateDataTypes::FLOAT },
+    ///     CreateColumns::new("Column_A", CreateDataTypes::VARCHAR(20 as usize)),
+    ///     CreateColumns::new("Column_B", CreateDataTypes::NUMBER(0, 0)),
+    ///     CreateColumns::new("Column_C", CreateDataTypes::FLOAT),
     /// ];
     ///
     /// conn.create()
@@ -187,6 +297,26 @@ pub trait QueryBuilder {
     ///     .build()?
     /// ```
     fn delete(&self, table: &str) -> DeleteProps;
+
+    /// Creates a new [`DropProps`] to start building a drop query for a table, view, or index.
+    ///
+    /// ```no_run
+    /// let conn = OracleConnect::new(connection_string, username, password)?;
+    /// conn.drop()
+    ///     .table("regional_sales")
+    ///     .if_exists()
+    ///     .build()?;
+    /// ```
+    fn drop(&self) -> DropProps;
+
+    /// Creates a new [`TruncateProps`] which removes every row in a table.
+    /// SQLite doesn't have TRUNCATE, so it runs a `DELETE FROM` and resets the table's AUTOINCREMENT sequence.
+    ///
+    /// ```no_run
+    /// let conn = OracleConnect::new(connection_string, username, password)?;
+    /// conn.truncate("regional_sales").build()?;
+    /// ```
+    fn truncate(&self, table: &str) -> TruncateProps;
 }
 
 #[derive(Debug, Clone)]
@@ -11,7 +11,18 @@ pub(crate) fn where_clause_value_format<T: ToSQLData>(items: Vec<T>) -> String {
             data_types::SQLDataTypes::Varchar(val) => format!("'{}'", val),
             data_types::SQLDataTypes::Number(val) => format!("{}", val),
             data_types::SQLDataTypes::Float(val) => format!("{}", val),
+            data_types::SQLDataTypes::Decimal(val) => val.to_plain_string(),
             data_types::SQLDataTypes::Date(val) => format!("'{}'", val),
+            data_types::SQLDataTypes::Time(val) => format!("'{}'", val),
+            data_types::SQLDataTypes::Timestamp(val) => format!("'{}'", val),
+            data_types::SQLDataTypes::TimestampTz(val) => {
+                format!("'{}'", val.format("%Y-%m-%d %H:%M:%S%.f %:z"))
+            }
+            data_types::SQLDataTypes::Interval(val) => format!("'{}'", val),
+            data_types::SQLDataTypes::Bool(val) => format!("{}", i64::from(val)),
+            data_types::SQLDataTypes::Json(val) => {
+                format!("'{}'", val.to_string().replace('\'', "''"))
+            }
             data_types::SQLDataTypes::NULL => format!("NULL"),
         })
         .collect::<Vec<String>>()
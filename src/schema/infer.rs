@@ -0,0 +1,157 @@
+use crate::{
+    Error, SQLImplementation,
+    data_types::{SQLDataTypes, ToSQLData},
+    sql_implementations::{OracleConnect, SQLiteConnect},
+    statements::{
+        create::{CreateDataTypes, CreateTable, sql_implementations::utils::ORACLE_VARCHAR_LIMIT},
+        insert::{
+            ParseOptions, data_conversion::format_grid,
+            sql_implementations::oracle::validation::get_col_indexes,
+        },
+    },
+};
+
+/// Options for [`infer_schema`](SQLImplementation::infer_schema).
+/// Defaults to looking at every row, nullable columns, exact `VARCHAR` sizes and no overrides.
+#[derive(Debug, Clone, Default)]
+pub struct InferOptions {
+    /// Only looks at this many rows, spread evenly across the grid.
+    /// The `VARCHAR` sizes and nullability are only as good as the sample.
+    pub sample_size: Option<usize>,
+    /// Makes the columns without any NULLs (or blank text) `NOT NULL`.
+    pub detect_nullability: bool,
+    /// Rounds `VARCHAR` sizes up to a multiple of this, e.g. `50` turns a `VARCHAR(23)` into a `VARCHAR(50)`.
+    /// `0` keeps the exact size.
+    pub varchar_headroom: usize,
+    /// Pairs of (column, type) used instead of the inferred type.
+    pub overrides: Vec<(String, CreateDataTypes)>,
+}
+
+impl InferOptions {
+    pub fn new() -> Self {
+        InferOptions::default()
+    }
+
+    /// Only looks at this many rows, spread evenly across the grid.
+    pub fn sample(mut self, sample_size: usize) -> Self {
+        self.sample_size = Some(sample_size.max(1));
+        self
+    }
+
+    pub fn detect_nullability(mut self) -> Self {
+        self.detect_nullability = true;
+        self
+    }
+
+    pub fn varchar_headroom(mut self, headroom: usize) -> Self {
+        self.varchar_headroom = headroom;
+        self
+    }
+
+    /// Uses this type for the column instead of the inferred one.
+    pub fn override_column(mut self, column: &str, data_type: CreateDataTypes) -> Self {
+        self.overrides.push((column.to_string(), data_type));
+        self
+    }
+}
+
+impl SQLImplementation {
+    /// Infers a [`CreateTable`] from a grid with a header, the way [`create_table`](crate::statements::insert::InsertBuilder::create_table)
+    /// does on insert, so the DDL can be reviewed or changed before it's built.
+    /// Text is parsed the same way as [`format_grid_strings`](crate::statements::insert::InsertBuilder::format_grid_strings).
+    ///
+    /// On Oracle, text longer than 4000 bytes becomes a `CLOB` instead of a `VARCHAR2`.
+    /// ```no_run
+    /// let options = InferOptions::new()
+    ///     .sample(1_000)
+    ///     .detect_nullability()
+    ///     .varchar_headroom(50)
+    ///     .override_column("zip_code", CreateDataTypes::VARCHAR(10));
+    /// let create_table = conn.infer_schema("regional_sales", data, options)?;
+    /// for column in &create_table.columns {
+    ///     println!("{} {:?} not null: {}", column.name, column.data_type, column.not_null);
+    /// }
+    /// create_table.build()?;
+    /// ```
+    pub fn infer_schema<T: ToSQLData>(
+        &self,
+        table: &str,
+        data: Vec<Vec<T>>,
+        options: InferOptions,
+    ) -> Result<CreateTable, Error> {
+        let insert_props = self.to_owned().insert_initialization(table, data)?;
+        let header = insert_props.header;
+        let mut sample = match options.sample_size {
+            Some(size) if size < insert_props.grid.len() => {
+                let len = insert_props.grid.len();
+                (0..size)
+                    .map(|idx| insert_props.grid[idx * len / size].to_owned())
+                    .collect::<Vec<Vec<SQLDataTypes>>>()
+            }
+            _ => insert_props.grid,
+        };
+        format_grid(&mut sample, &ParseOptions::default());
+
+        let is_oracle = matches!(self, SQLImplementation::Oracle(_));
+        let mut columns = get_col_indexes(&sample)?.to_create_columns(&header);
+        for (idx, column) in columns.iter_mut().enumerate() {
+            let data_type = options
+                .overrides
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(&column.name))
+                .map(|(_, data_type)| data_type.clone());
+            column.data_type = match (data_type, &column.data_type) {
+                (Some(data_type), _) => data_type,
+                (None, CreateDataTypes::VARCHAR(size))
+                    if is_oracle && *size > ORACLE_VARCHAR_LIMIT =>
+                {
+                    CreateDataTypes::CLOB
+                }
+                (None, CreateDataTypes::VARCHAR(size)) if options.varchar_headroom > 0 => {
+                    let headroom = options.varchar_headroom;
+                    let size = (*size).max(1).div_ceil(headroom) * headroom;
+                    match is_oracle {
+                        true => CreateDataTypes::VARCHAR(size.min(ORACLE_VARCHAR_LIMIT)),
+                        false => CreateDataTypes::VARCHAR(size),
+                    }
+                }
+                (None, data_type) => data_type.clone(),
+            };
+            if options.detect_nullability {
+                column.not_null = !sample.is_empty()
+                    && sample
+                        .iter()
+                        .all(|row| !matches!(row.get(idx), None | Some(SQLDataTypes::NULL)));
+            }
+        }
+
+        Ok(self
+            .to_owned()
+            .create_initialization()
+            .table(table, columns))
+    }
+}
+
+impl OracleConnect {
+    /// See [`SQLImplementation::infer_schema`].
+    pub fn infer_schema<T: ToSQLData>(
+        &self,
+        table: &str,
+        data: Vec<Vec<T>>,
+        options: InferOptions,
+    ) -> Result<CreateTable, Error> {
+        SQLImplementation::Oracle(self.clone()).infer_schema(table, data, options)
+    }
+}
+
+impl SQLiteConnect {
+    /// See [`SQLImplementation::infer_schema`].
+    pub fn infer_schema<T: ToSQLData>(
+        &self,
+        table: &str,
+        data: Vec<Vec<T>>,
+        options: InferOptions,
+    ) -> Result<CreateTable, Error> {
+        SQLImplementation::SQLite(self.clone()).infer_schema(table, data, options)
+    }
+}
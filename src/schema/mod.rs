@@ -0,0 +1,150 @@
+use crate::{
+    Error, SQLImplementation,
+    data_types::SQLDataTypes,
+    statements::create::{
+        CreateColumns, CreateDataTypes, CreateTable, TableForeignKey,
+        sql_implementations::utils::ORACLE_VARCHAR_LIMIT,
+    },
+};
+
+pub mod infer;
+pub mod oracle;
+pub mod sqlite;
+
+/// The structure of an existing table, returned by `describe_table`.
+#[derive(Debug, Clone)]
+pub struct TableSchema {
+    /// The Oracle owner of the table. SQLite tables are always in `main`.
+    pub owner: String,
+    pub table: String,
+    pub columns: Vec<ColumnSchema>,
+    pub primary_key: Vec<String>,
+    pub foreign_keys: Vec<ForeignKeySchema>,
+    pub indexes: Vec<IndexSchema>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ColumnSchema {
+    pub name: String,
+    /// The type exactly as the database reports it, e.g. `VARCHAR2(20)` or `TIMESTAMP(6)`.
+    pub declared_type: String,
+    pub data_type: CreateDataTypes,
+    pub nullable: bool,
+    /// The default as a SQL expression, e.g. `'OPEN'` or `SYSDATE`.
+    pub default: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ForeignKeySchema {
+    pub columns: Vec<String>,
+    pub referenced_table: String,
+    pub referenced_columns: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct IndexSchema {
+    pub name: String,
+    /// Expression indexes show up as the generated column name on Oracle and an empty string on SQLite.
+    pub columns: Vec<String>,
+    pub unique: bool,
+}
+
+impl SQLImplementation {
+    /// Gets the columns, keys, and indexes of a table. See [`TableSchema`].
+    pub fn describe_table(&self, table: &str) -> Result<TableSchema, Error> {
+        match self {
+            SQLImplementation::Oracle(connect) => connect.describe_table(table),
+            SQLImplementation::SQLite(connect) => connect.describe_table(table),
+        }
+    }
+
+    /// Rebuilds the [`CreateTable`] of an existing table from its schema.
+    /// Unique indexes are carried over as unique constraints, other indexes aren't.
+    /// Defaults that aren't plain values (like `SYSDATE` or `CURRENT_TIMESTAMP`) aren't carried over.
+    pub fn table_ddl(&self, table: &str) -> Result<CreateTable, Error> {
+        let schema = self.describe_table(table)?;
+        Ok(schema.to_create_table(self.to_owned()))
+    }
+}
+
+impl TableSchema {
+    pub(crate) fn to_create_table(&self, connect: SQLImplementation) -> CreateTable {
+        let single_pk = self.primary_key.len() == 1;
+        // The primary key's own index, and expression indexes SQLite lists with empty column names, aren't constraints
+        let unique = self
+            .indexes
+            .iter()
+            .filter(|index| index.unique && index.columns != self.primary_key)
+            .filter(|index| index.columns.iter().all(|column| !column.is_empty()))
+            .map(|index| index.columns.to_owned())
+            .collect::<Vec<Vec<String>>>();
+        let is_key = |name: &String| {
+            self.primary_key.contains(name)
+                || unique.iter().any(|columns| columns.contains(name))
+                || self
+                    .foreign_keys
+                    .iter()
+                    .any(|foreign_key| foreign_key.columns.contains(name))
+        };
+        let columns = self
+            .columns
+            .iter()
+            .map(|column| {
+                // A CLOB can't be part of a key on Oracle
+                let data_type = match column.data_type {
+                    CreateDataTypes::CLOB if is_key(&column.name) => {
+                        CreateDataTypes::VARCHAR(ORACLE_VARCHAR_LIMIT)
+                    }
+                    _ => column.data_type.clone(),
+                };
+                let mut create_column = CreateColumns::new(&column.name, data_type);
+                create_column.not_null = !column.nullable;
+                create_column.primary_key = single_pk && self.primary_key[0] == column.name;
+                create_column.default = column.default.as_deref().and_then(parse_default);
+                create_column
+            })
+            .collect::<Vec<CreateColumns>>();
+
+        CreateTable {
+            connect,
+            columns,
+            table: self.table.to_string(),
+            primary_key: if single_pk {
+                Vec::new()
+            } else {
+                self.primary_key.to_owned()
+            },
+            unique,
+            foreign_keys: self
+                .foreign_keys
+                .iter()
+                .map(|foreign_key| TableForeignKey {
+                    columns: foreign_key.columns.to_owned(),
+                    table: foreign_key.referenced_table.to_string(),
+                    referenced_columns: foreign_key.referenced_columns.to_owned(),
+                })
+                .collect(),
+        }
+    }
+}
+
+// Only literal defaults can be turned back into a value, expressions return None
+fn parse_default(default: &str) -> Option<SQLDataTypes> {
+    let mut val = default.trim();
+    while let Some(inner) = val.strip_prefix('(').and_then(|val| val.strip_suffix(')')) {
+        val = inner.trim();
+    }
+    if let Some(inner) = val
+        .strip_prefix('\'')
+        .and_then(|val| val.strip_suffix('\''))
+    {
+        return Some(SQLDataTypes::Varchar(inner.replace("''", "'")));
+    }
+    if let Ok(int) = val.parse::<i64>() {
+        return Some(SQLDataTypes::Number(int));
+    }
+    if let Ok(float) = val.parse::<f64>() {
+        return Some(SQLDataTypes::Float(float));
+    }
+    None
+}
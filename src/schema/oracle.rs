@@ -0,0 +1,237 @@
+use oracle::sql_type::OracleType;
+
+use crate::{
+    Error, SQLImplementation,
+    sql_implementations::OracleConnect,
+    statements::create::{CreateDataTypes, CreateTable},
+};
+
+use super::{ColumnSchema, ForeignKeySchema, IndexSchema, TableSchema};
+
+impl OracleConnect {
+    /// Gets the columns, keys, and indexes of a table from `all_tab_columns`, `all_constraints` and `all_indexes`.
+    /// Tables in another schema can be described by passing in `owner.table`, otherwise the current user is the owner.
+    ///
+    /// ```no_run
+    /// let conn = OracleConnect::new(connection_string, username, password)?;
+    /// let schema = conn.describe_table("hr.employees")?;
+    /// println!("{:?}", schema.primary_key);
+    /// ```
+    pub fn describe_table(&self, table: &str) -> Result<TableSchema, Error> {
+        let conn: oracle::Connection =
+            oracle::Connection::connect(&self.username, &self.password, &self.connection_string)?;
+
+        let (owner, table) = match table.split_once('.') {
+            Some((owner, table)) => (owner.to_ascii_uppercase(), table.to_ascii_uppercase()),
+            None => (
+                conn.query_row_as::<String>("SELECT USER FROM dual", &[])?,
+                table.to_ascii_uppercase(),
+            ),
+        };
+
+        let sql = "
+            SELECT column_name, data_type, char_length, data_precision, data_scale, nullable, data_default
+            FROM all_tab_columns
+            WHERE owner = :1 AND table_name = :2
+            ORDER BY column_id";
+        let mut columns = Vec::new();
+        for row in conn.query(sql, &[&owner, &table])? {
+            let row = row?;
+            let data_type: String = row.get(1)?;
+            let char_length: Option<usize> = row.get(2)?;
+            let precision: Option<usize> = row.get(3)?;
+            // Negative when a NUMBER rounds to the left of the decimal point, e.g. NUMBER(5, -2)
+            let scale: Option<i64> = row.get(4)?;
+            let default: Option<String> = row.get(6)?;
+            columns.push(ColumnSchema {
+                name: row.get(0)?,
+                declared_type: fmt_declared_type(&data_type, char_length, precision, scale),
+                data_type: oracle_declared_type(&data_type, char_length, precision, scale),
+                nullable: row.get::<usize, String>(5)? == "Y",
+                default: default
+                    .map(|val| val.trim().to_string())
+                    .filter(|val| !val.is_empty()),
+            });
+        }
+        if columns.is_empty() {
+            return Err(Error::TableDoesNotExist);
+        }
+
+        let sql = "
+            SELECT cc.column_name
+            FROM all_constraints c
+            JOIN all_cons_columns cc ON cc.owner = c.owner AND cc.constraint_name = c.constraint_name
+            WHERE c.constraint_type = 'P' AND c.owner = :1 AND c.table_name = :2
+            ORDER BY cc.position";
+        let primary_key = conn
+            .query_as::<String>(sql, &[&owner, &table])?
+            .collect::<Result<Vec<String>, oracle::Error>>()?;
+
+        let sql = "
+            SELECT c.constraint_name, cc.column_name, r.table_name, rc.column_name
+            FROM all_constraints c
+            JOIN all_cons_columns cc ON cc.owner = c.owner AND cc.constraint_name = c.constraint_name
+            JOIN all_constraints r ON r.owner = c.r_owner AND r.constraint_name = c.r_constraint_name
+            JOIN all_cons_columns rc
+                ON rc.owner = r.owner AND rc.constraint_name = r.constraint_name AND rc.position = cc.position
+            WHERE c.constraint_type = 'R' AND c.owner = :1 AND c.table_name = :2
+            ORDER BY c.constraint_name, cc.position";
+        let mut foreign_keys: Vec<(String, ForeignKeySchema)> = Vec::new();
+        for row in conn.query_as::<(String, String, String, String)>(sql, &[&owner, &table])? {
+            let (constraint, column, referenced_table, referenced_column) = row?;
+            match foreign_keys.last_mut() {
+                Some((name, foreign_key)) if *name == constraint => {
+                    foreign_key.columns.push(column);
+                    foreign_key.referenced_columns.push(referenced_column);
+                }
+                _ => foreign_keys.push((
+                    constraint,
+                    ForeignKeySchema {
+                        columns: vec![column],
+                        referenced_table,
+                        referenced_columns: vec![referenced_column],
+                    },
+                )),
+            }
+        }
+
+        let sql = "
+            SELECT i.index_name, i.uniqueness, ic.column_name
+            FROM all_indexes i
+            JOIN all_ind_columns ic ON ic.index_owner = i.owner AND ic.index_name = i.index_name
+            WHERE i.table_owner = :1 AND i.table_name = :2
+            ORDER BY i.index_name, ic.column_position";
+        let mut indexes: Vec<IndexSchema> = Vec::new();
+        for row in conn.query_as::<(String, String, String)>(sql, &[&owner, &table])? {
+            let (name, uniqueness, column) = row?;
+            match indexes.last_mut() {
+                Some(index) if index.name == name => index.columns.push(column),
+                _ => indexes.push(IndexSchema {
+                    name,
+                    columns: vec![column],
+                    unique: uniqueness == "UNIQUE",
+                }),
+            }
+        }
+
+        Ok(TableSchema {
+            owner,
+            table,
+            columns,
+            primary_key,
+            foreign_keys: foreign_keys.into_iter().map(|(_, fk)| fk).collect(),
+            indexes,
+        })
+    }
+
+    /// Rebuilds the [`CreateTable`] of an existing table, including its keys.
+    /// The connection can be swapped out or [`CreateTable::to_sql_for`] used to recreate it in another database.
+    ///
+    /// ```no_run
+    /// let create_table = conn.table_ddl("regional_sales")?;
+    /// ```
+    pub fn table_ddl(&self, table: &str) -> Result<CreateTable, Error> {
+        SQLImplementation::Oracle(self.clone()).table_ddl(table)
+    }
+}
+
+/// Maps the columns of `all_tab_columns` onto a [`CreateDataTypes`].
+pub(crate) fn oracle_declared_type(
+    data_type: &str,
+    char_length: Option<usize>,
+    precision: Option<usize>,
+    scale: Option<i64>,
+) -> CreateDataTypes {
+    match data_type {
+        "VARCHAR2" | "NVARCHAR2" | "CHAR" | "NCHAR" | "VARCHAR" => {
+            CreateDataTypes::VARCHAR(char_length.unwrap_or(4000))
+        }
+        "NUMBER" => match scale {
+            // A negative scale holds more digits than its precision, which only an unconstrained NUMBER can
+            Some(scale) if scale < 0 => CreateDataTypes::NUMBER(0, 0),
+            _ => CreateDataTypes::NUMBER(precision.unwrap_or(0), scale.unwrap_or(0) as usize),
+        },
+        "FLOAT" | "BINARY_FLOAT" | "BINARY_DOUBLE" => CreateDataTypes::FLOAT,
+        "DATE" => CreateDataTypes::DATE,
+        "BOOLEAN" => CreateDataTypes::BOOL,
+        "JSON" => CreateDataTypes::JSON,
+        "CLOB" | "NCLOB" | "LONG" => CreateDataTypes::CLOB,
+        // TIMESTAMP(6), TIMESTAMP(6) WITH TIME ZONE, INTERVAL DAY(2) TO SECOND(6), etc..
+        val if val.starts_with("TIMESTAMP") && val.contains("TIME ZONE") => {
+            CreateDataTypes::TIMESTAMPTZ
+        }
+        val if val.starts_with("TIMESTAMP") => CreateDataTypes::TIMESTAMP,
+        val if val.starts_with("INTERVAL YEAR") => CreateDataTypes::INTERVALYM,
+        val if val.starts_with("INTERVAL") => CreateDataTypes::INTERVAL,
+        // RAW, BLOB, etc..
+        _ => CreateDataTypes::VARCHAR(char_length.filter(|len| *len > 0).unwrap_or(4000)),
+    }
+}
+
+/// Gets the name and type of each column a query returns, without fetching any rows.
+pub(crate) fn oracle_query_columns(
+    conn_info: &OracleConnect,
+    query: &str,
+) -> Result<Vec<(String, CreateDataTypes)>, Error> {
+    let conn: oracle::Connection = oracle::Connection::connect(
+        &conn_info.username,
+        &conn_info.password,
+        &conn_info.connection_string,
+    )?;
+    let mut stmt = conn
+        .statement(&format!("SELECT * FROM ({query}) WHERE 1 = 0"))
+        .build()?;
+    let rows = stmt.query(&[])?;
+    Ok(rows
+        .column_info()
+        .iter()
+        .map(|column| {
+            (
+                column.name().to_string(),
+                oracle_result_type(column.oracle_type()),
+            )
+        })
+        .collect())
+}
+
+fn oracle_result_type(oracle_type: &OracleType) -> CreateDataTypes {
+    match oracle_type {
+        OracleType::Varchar2(len)
+        | OracleType::NVarchar2(len)
+        | OracleType::Char(len)
+        | OracleType::NChar(len) => CreateDataTypes::VARCHAR(*len as usize),
+        // A scale of -127 is an unconstrained NUMBER, like the result of COUNT(*)
+        OracleType::Number(precision, scale) => {
+            CreateDataTypes::NUMBER(*precision as usize, (*scale).max(0) as usize)
+        }
+        OracleType::Int64 | OracleType::UInt64 => CreateDataTypes::NUMBER(0, 0),
+        OracleType::Float(_) | OracleType::BinaryFloat | OracleType::BinaryDouble => {
+            CreateDataTypes::FLOAT
+        }
+        OracleType::Date => CreateDataTypes::DATE,
+        OracleType::Timestamp(_) => CreateDataTypes::TIMESTAMP,
+        OracleType::TimestampTZ(_) | OracleType::TimestampLTZ(_) => CreateDataTypes::TIMESTAMPTZ,
+        OracleType::IntervalDS(_, _) => CreateDataTypes::INTERVAL,
+        OracleType::IntervalYM(_) => CreateDataTypes::INTERVALYM,
+        OracleType::Boolean => CreateDataTypes::BOOL,
+        OracleType::Json => CreateDataTypes::JSON,
+        OracleType::CLOB | OracleType::NCLOB | OracleType::Long => CreateDataTypes::CLOB,
+        _ => CreateDataTypes::VARCHAR(4000),
+    }
+}
+
+fn fmt_declared_type(
+    data_type: &str,
+    char_length: Option<usize>,
+    precision: Option<usize>,
+    scale: Option<i64>,
+) -> String {
+    match (data_type, char_length, precision, scale) {
+        ("NUMBER", _, Some(precision), Some(0)) => format!("NUMBER({precision})"),
+        ("NUMBER", _, Some(precision), Some(scale)) => format!("NUMBER({precision}, {scale})"),
+        ("VARCHAR2" | "NVARCHAR2" | "CHAR" | "NCHAR", Some(len), _, _) => {
+            format!("{data_type}({len})")
+        }
+        _ => data_type.to_string(),
+    }
+}
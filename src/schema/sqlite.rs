@@ -0,0 +1,188 @@
+use crate::{
+    Error, SQLImplementation,
+    sql_implementations::SQLiteConnect,
+    statements::create::{CreateDataTypes, CreateTable},
+};
+
+use super::{ColumnSchema, ForeignKeySchema, IndexSchema, TableSchema};
+
+impl SQLiteConnect {
+    /// Gets the columns, keys, and indexes of a table using `PRAGMA table_info`, `foreign_key_list` and `index_list`.
+    ///
+    /// ```no_run
+    /// let conn = SQLiteConnect::from_path("path/to/file.db");
+    /// let schema = conn.describe_table("regional_sales")?;
+    /// for column in schema.columns {
+    ///     println!("{} {:?} nullable: {}", column.name, column.data_type, column.nullable);
+    /// }
+    /// ```
+    pub fn describe_table(&self, table: &str) -> Result<TableSchema, Error> {
+        let conn = self.initialize_connection()?;
+
+        let mut primary_key: Vec<(i64, String)> = Vec::new();
+        let mut stmt = conn.prepare(
+            "SELECT name, type, \"notnull\", dflt_value, pk FROM pragma_table_info(?1) ORDER BY cid",
+        )?;
+        let columns = stmt
+            .query_map([table], |row| {
+                let name: String = row.get(0)?;
+                let declared_type: String = row.get(1)?;
+                let pk: i64 = row.get(4)?;
+                if pk > 0 {
+                    primary_key.push((pk, name.clone()));
+                }
+                Ok(ColumnSchema {
+                    data_type: sqlite_declared_type(&declared_type),
+                    name,
+                    declared_type,
+                    // primary keys can't be null even if they aren't declared NOT NULL
+                    nullable: row.get::<usize, i64>(2)? == 0 && pk == 0,
+                    default: row.get(3)?,
+                })
+            })?
+            .collect::<Result<Vec<ColumnSchema>, rusqlite::Error>>()?;
+        if columns.is_empty() {
+            return Err(Error::TableDoesNotExist);
+        }
+        primary_key.sort();
+
+        let mut foreign_keys: Vec<(i64, ForeignKeySchema)> = Vec::new();
+        let mut stmt = conn.prepare(
+            "SELECT id, \"table\", \"from\", \"to\" FROM pragma_foreign_key_list(?1) ORDER BY id, seq",
+        )?;
+        let mut rows = stmt.query([table])?;
+        while let Some(row) = rows.next()? {
+            let id: i64 = row.get(0)?;
+            let from: String = row.get(2)?;
+            // `to` is NULL when the key references the other table's primary key
+            let to: Option<String> = row.get(3)?;
+            let to = to.unwrap_or_default();
+            match foreign_keys.last_mut() {
+                Some((last_id, foreign_key)) if *last_id == id => {
+                    foreign_key.columns.push(from);
+                    foreign_key.referenced_columns.push(to);
+                }
+                _ => foreign_keys.push((
+                    id,
+                    ForeignKeySchema {
+                        columns: vec![from],
+                        referenced_table: row.get(1)?,
+                        referenced_columns: vec![to],
+                    },
+                )),
+            }
+        }
+
+        let mut stmt = conn.prepare("SELECT name, \"unique\" FROM pragma_index_list(?1)")?;
+        let index_list = stmt
+            .query_map([table], |row| {
+                Ok((row.get::<usize, String>(0)?, row.get::<usize, i64>(1)? == 1))
+            })?
+            .collect::<Result<Vec<(String, bool)>, rusqlite::Error>>()?;
+        let mut stmt = conn.prepare("SELECT name FROM pragma_index_info(?1) ORDER BY seqno")?;
+        let indexes = index_list
+            .into_iter()
+            .map(|(name, unique)| -> Result<IndexSchema, Error> {
+                let columns = stmt
+                    .query_map([&name], |row| row.get::<usize, Option<String>>(0))?
+                    .map(|col| col.map(|col| col.unwrap_or_default()))
+                    .collect::<Result<Vec<String>, rusqlite::Error>>()?;
+                Ok(IndexSchema {
+                    name,
+                    columns,
+                    unique,
+                })
+            })
+            .collect::<Result<Vec<IndexSchema>, Error>>()?;
+
+        Ok(TableSchema {
+            owner: String::from("main"),
+            table: table.to_string(),
+            columns,
+            primary_key: primary_key.into_iter().map(|(_, name)| name).collect(),
+            foreign_keys: foreign_keys.into_iter().map(|(_, fk)| fk).collect(),
+            indexes,
+        })
+    }
+
+    /// Rebuilds the [`CreateTable`] of an existing table, including its keys.
+    /// The connection can be swapped out or [`CreateTable::to_sql_for`] used to recreate it in another database.
+    ///
+    /// ```no_run
+    /// let create_table = conn.table_ddl("regional_sales")?;
+    /// ```
+    pub fn table_ddl(&self, table: &str) -> Result<CreateTable, Error> {
+        SQLImplementation::SQLite(self.clone()).table_ddl(table)
+    }
+}
+
+/// Maps a declared SQLite column type onto a [`CreateDataTypes`], following SQLite's own affinity rules
+/// for anything that isn't one of the types this crate creates.
+pub(crate) fn sqlite_declared_type(declared_type: &str) -> CreateDataTypes {
+    let upper = declared_type.trim().to_ascii_uppercase();
+    let args = type_arguments(&upper);
+    let base = upper.split('(').next().unwrap_or_default().trim();
+
+    match base {
+        "DATE" => CreateDataTypes::DATE,
+        "TIME" => CreateDataTypes::TIME,
+        "TIMESTAMP" | "DATETIME" => CreateDataTypes::TIMESTAMP,
+        "TIMESTAMPTZ" => CreateDataTypes::TIMESTAMPTZ,
+        "INTERVAL" => CreateDataTypes::INTERVAL,
+        "INTERVALYM" => CreateDataTypes::INTERVALYM,
+        "BOOL" | "BOOLEAN" => CreateDataTypes::BOOL,
+        "JSON" => CreateDataTypes::JSON,
+        "CLOB" => CreateDataTypes::CLOB,
+        "FLOAT" | "REAL" | "DOUBLE" | "DOUBLE PRECISION" => CreateDataTypes::FLOAT,
+        "NUMBER" | "NUMERIC" | "DECIMAL" => match args[..] {
+            [precision] => CreateDataTypes::NUMBER(precision, 0),
+            [precision, scale, ..] => CreateDataTypes::NUMBER(precision, scale),
+            [] => CreateDataTypes::NUMBER(0, 0),
+        },
+        _ if base.contains("INT") => CreateDataTypes::NUMBER(0, 0),
+        _ if base.contains("CHAR") || base.contains("CLOB") || base.contains("TEXT") => {
+            match args.first() {
+                Some(size) => CreateDataTypes::VARCHAR(*size),
+                // Unsized TEXT can hold any length, which only a CLOB can on Oracle
+                None if !base.contains("CHAR") => CreateDataTypes::CLOB,
+                None => CreateDataTypes::VARCHAR(4000),
+            }
+        }
+        _ if base.contains("REAL") || base.contains("FLOA") || base.contains("DOUB") => {
+            CreateDataTypes::FLOAT
+        }
+        // BLOB and untyped columns
+        _ => CreateDataTypes::VARCHAR(4000),
+    }
+}
+
+/// Gets the name and type of each column a query returns from the declared types of the columns it selects.
+pub(crate) fn sqlite_query_columns(
+    conn_info: &SQLiteConnect,
+    query: &str,
+) -> Result<Vec<(String, CreateDataTypes)>, Error> {
+    let conn = conn_info.initialize_connection()?;
+    let stmt = conn.prepare(query)?;
+    Ok(stmt
+        .columns()
+        .iter()
+        .map(|column| {
+            let data_type = match column.decl_type() {
+                Some(declared_type) => sqlite_declared_type(declared_type),
+                // Expressions like COUNT(*) don't have a declared type, NUMBER keeps numbers as numbers and text as text
+                None => CreateDataTypes::NUMBER(0, 0),
+            };
+            (column.name().to_string(), data_type)
+        })
+        .collect())
+}
+
+fn type_arguments(declared_type: &str) -> Vec<usize> {
+    match (declared_type.find('('), declared_type.find(')')) {
+        (Some(start), Some(end)) if start < end => declared_type[start + 1..end]
+            .split(',')
+            .filter_map(|arg| arg.trim().parse::<usize>().ok())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
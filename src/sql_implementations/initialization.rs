@@ -5,8 +5,10 @@ use crate::{
         alter::AlterProps,
         create::CreateProps,
         delete::DeleteProps,
-        insert::InsertProps,
+        drop::DropProps,
+        insert::{InsertFromProps, InsertIterProps, InsertProps, ParseOptions},
         select::{Column, Limit, SelectProps},
+        truncate::TruncateProps,
         update::UpdateProps,
     },
     utils::remove_invalid_chars,
@@ -70,9 +72,102 @@ impl SQLImplementation {
             table: table.to_string(),
             header,
             create: false,
+            on_conflict: None,
+            column_map: Vec::new(),
+            chunk_size: 10_000,
+            checkpoint: None,
+            resume: false,
+            coerce: false,
+            evolve: false,
+            parse_options: ParseOptions::default(),
         })
     }
 
+    pub(crate) fn insert_rows_initialization<T: ToSQLData>(
+        self,
+        table: &str,
+        rows: Vec<Vec<T>>,
+    ) -> Result<InsertProps, Error> {
+        if rows.is_empty() {
+            return Err(Error::NoData);
+        }
+        let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+        let schema = self.describe_table(table.trim())?;
+        if width > schema.columns.len() {
+            return Err(Error::ColumnCountMismatch(schema.columns.len(), width));
+        }
+        let header = schema
+            .columns
+            .into_iter()
+            .take(width)
+            .map(|column| column.name)
+            .collect::<Vec<String>>();
+        let grid = rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| cell.to_sql_fmt())
+                    .collect::<Vec<SQLDataTypes>>()
+            })
+            .collect::<Vec<Vec<SQLDataTypes>>>();
+        Ok(InsertProps {
+            connect: self,
+            grid,
+            table: table.trim().to_string(),
+            header,
+            create: false,
+            on_conflict: None,
+            column_map: Vec::new(),
+            chunk_size: 10_000,
+            checkpoint: None,
+            resume: false,
+            coerce: false,
+            evolve: false,
+            parse_options: ParseOptions::default(),
+        })
+    }
+
+    pub(crate) fn insert_iter_initialization<'a, T, I>(
+        self,
+        table: &str,
+        header: &[&str],
+        rows: I,
+    ) -> InsertIterProps<'a>
+    where
+        T: ToSQLData + 'a,
+        I: IntoIterator<Item = Vec<T>>,
+        I::IntoIter: 'a,
+    {
+        let rows = rows.into_iter().map(|row| {
+            row.iter()
+                .map(|cell| cell.to_sql_fmt())
+                .collect::<Vec<SQLDataTypes>>()
+        });
+        InsertIterProps {
+            connect: self,
+            table: table.trim().to_string(),
+            header: header.iter().map(|head| head.to_string()).collect(),
+            rows: Box::new(rows),
+            batch_size: 10_000,
+            create: false,
+        }
+    }
+
+    pub(crate) fn insert_from_initialization(
+        self,
+        table: &str,
+        columns: &[&str],
+        select: SelectProps,
+    ) -> InsertFromProps {
+        InsertFromProps {
+            connect: self,
+            table: table.trim().to_string(),
+            columns: columns.iter().map(|col| col.to_string()).collect(),
+            select,
+            create: false,
+        }
+    }
+
     pub(crate) fn create_initialization(self) -> CreateProps {
         CreateProps { connect: self }
     }
@@ -81,6 +176,17 @@ impl SQLImplementation {
         AlterProps { connect: self }
     }
 
+    pub(crate) fn drop_initialization(self) -> DropProps {
+        DropProps { connect: self }
+    }
+
+    pub(crate) fn truncate_initialization(self, table: &str) -> TruncateProps {
+        TruncateProps {
+            connect: self,
+            table: table.to_string(),
+        }
+    }
+
     pub(crate) fn delete_initialization(self, table: &str) -> DeleteProps {
         let table = table.to_string();
         DeleteProps {
@@ -1,13 +1,17 @@
+use oracle::sql_type::OracleType;
+
 use crate::{
     Error, QueryBuilder, SQLImplementation,
-    data_types::ToSQLData,
+    data_types::{SQLDataTypes, ToSQLData},
     sql_implementations::OracleConnect,
     statements::{
         alter::AlterProps,
-        create::CreateProps,
+        create::{CreateDataTypes, CreateProps},
         delete::DeleteProps,
-        insert::InsertProps,
+        drop::DropProps,
+        insert::{InsertFromProps, InsertIterProps, InsertProps},
         select::{Column, SelectProps},
+        truncate::TruncateProps,
         update::UpdateProps,
     },
 };
@@ -39,6 +43,27 @@ impl QueryBuilder for OracleConnect {
         SQLImplementation::Oracle(self.clone()).insert_initialization(table, data)
     }
 
+    fn insert_rows<T: ToSQLData>(
+        &self,
+        table: &str,
+        rows: Vec<Vec<T>>,
+    ) -> Result<InsertProps, Error> {
+        SQLImplementation::Oracle(self.clone()).insert_rows_initialization(table, rows)
+    }
+
+    fn insert_from(&self, table: &str, columns: &[&str], select: SelectProps) -> InsertFromProps {
+        SQLImplementation::Oracle(self.clone()).insert_from_initialization(table, columns, select)
+    }
+
+    fn insert_iter<'a, T, I>(&self, table: &str, header: &[&str], rows: I) -> InsertIterProps<'a>
+    where
+        T: ToSQLData + 'a,
+        I: IntoIterator<Item = Vec<T>>,
+        I::IntoIter: 'a,
+    {
+        SQLImplementation::Oracle(self.clone()).insert_iter_initialization(table, header, rows)
+    }
+
     fn create(&self) -> CreateProps {
         SQLImplementation::Oracle(self.clone()).create_initialization()
     }
@@ -50,4 +75,77 @@ impl QueryBuilder for OracleConnect {
     fn delete(&self, table: &str) -> DeleteProps {
         SQLImplementation::Oracle(self.clone()).delete_initialization(table)
     }
+
+    fn drop(&self) -> DropProps {
+        SQLImplementation::Oracle(self.clone()).drop_initialization()
+    }
+
+    fn truncate(&self, table: &str) -> TruncateProps {
+        SQLImplementation::Oracle(self.clone()).truncate_initialization(table)
+    }
+}
+
+/// Out-bind placeholders for `RETURNING ... INTO`, typed from the table's columns.
+/// Columns that aren't in the table (like `ROWID`) are returned as text.
+pub(crate) fn returning_binds(
+    conn_info: &OracleConnect,
+    table: &str,
+    columns: &[String],
+) -> Result<Vec<OracleType>, Error> {
+    let schema = conn_info.describe_table(table)?;
+    Ok(columns
+        .iter()
+        .map(|name| {
+            let data_type = schema
+                .columns
+                .iter()
+                .find(|column| column.name.eq_ignore_ascii_case(name))
+                .map(|column| &column.data_type);
+            match data_type {
+                Some(CreateDataTypes::VARCHAR(len)) => OracleType::Varchar2(*len as u32),
+                Some(CreateDataTypes::DATE) => OracleType::Date,
+                Some(CreateDataTypes::TIMESTAMP) => OracleType::Timestamp(9),
+                Some(CreateDataTypes::TIMESTAMPTZ) => OracleType::TimestampTZ(9),
+                Some(CreateDataTypes::INTERVAL) => OracleType::IntervalDS(9, 9),
+                Some(CreateDataTypes::INTERVALYM) => OracleType::IntervalYM(9),
+                Some(CreateDataTypes::JSON | CreateDataTypes::CLOB) => OracleType::CLOB,
+                Some(
+                    CreateDataTypes::NUMBER(_, _) | CreateDataTypes::FLOAT | CreateDataTypes::BOOL,
+                ) => OracleType::Number(0, 0),
+                Some(CreateDataTypes::TIME) | None => OracleType::Varchar2(4000),
+            }
+        })
+        .collect())
+}
+
+/// Formats ` RETURNING col_a, col_b INTO :n, :n+1` with the out-binds starting after the statement's own binds.
+pub(crate) fn fmt_returning_into(columns: &[String], start: usize) -> String {
+    let binds = (0..columns.len())
+        .map(|idx| format!(":{}", start + idx + 1))
+        .collect::<Vec<String>>();
+    format!(
+        " RETURNING {} INTO {}",
+        columns.join(", "),
+        binds.join(", ")
+    )
+}
+
+/// Oracle returns each out-bind as a column of values, so they're turned back into rows.
+pub(crate) fn returned_rows(
+    stmt: &oracle::Statement,
+    start: usize,
+    column_size: usize,
+) -> Result<Vec<Vec<SQLDataTypes>>, Error> {
+    let columns = (0..column_size)
+        .map(|idx| stmt.returned_values::<usize, SQLDataTypes>(start + idx + 1))
+        .collect::<Result<Vec<Vec<SQLDataTypes>>, oracle::Error>>()?;
+    let row_count = columns.first().map(|column| column.len()).unwrap_or(0);
+    Ok((0..row_count)
+        .map(|row| {
+            columns
+                .iter()
+                .map(|column| column[row].to_owned())
+                .collect()
+        })
+        .collect())
 }
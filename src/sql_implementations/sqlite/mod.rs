@@ -1,12 +1,17 @@
 use crate::{
     Error, QueryBuilder, SQLImplementation,
-    data_types::{SQLDataTypes, ToSQLData},
+    data_types::{
+        SQLDataTypes, ToSQLData,
+        sqlite::{declared_types, sqlite_cell},
+    },
     statements::{
         alter::AlterProps,
         create::CreateProps,
         delete::DeleteProps,
-        insert::InsertProps,
+        drop::DropProps,
+        insert::{InsertFromProps, InsertIterProps, InsertProps},
         select::{Column, SelectProps},
+        truncate::TruncateProps,
         update::UpdateProps,
     },
 };
@@ -82,6 +87,27 @@ impl QueryBuilder for SQLiteConnect {
         SQLImplementation::SQLite(self.clone()).insert_initialization(table, data)
     }
 
+    fn insert_rows<T: ToSQLData>(
+        &self,
+        table: &str,
+        rows: Vec<Vec<T>>,
+    ) -> Result<InsertProps, Error> {
+        SQLImplementation::SQLite(self.clone()).insert_rows_initialization(table, rows)
+    }
+
+    fn insert_from(&self, table: &str, columns: &[&str], select: SelectProps) -> InsertFromProps {
+        SQLImplementation::SQLite(self.clone()).insert_from_initialization(table, columns, select)
+    }
+
+    fn insert_iter<'a, T, I>(&self, table: &str, header: &[&str], rows: I) -> InsertIterProps<'a>
+    where
+        T: ToSQLData + 'a,
+        I: IntoIterator<Item = Vec<T>>,
+        I::IntoIter: 'a,
+    {
+        SQLImplementation::SQLite(self.clone()).insert_iter_initialization(table, header, rows)
+    }
+
     fn create(&self) -> CreateProps {
         SQLImplementation::SQLite(self.clone()).create_initialization()
     }
@@ -93,4 +119,30 @@ impl QueryBuilder for SQLiteConnect {
     fn delete(&self, table: &str) -> DeleteProps {
         SQLImplementation::SQLite(self.clone()).delete_initialization(table)
     }
+
+    fn drop(&self) -> DropProps {
+        SQLImplementation::SQLite(self.clone()).drop_initialization()
+    }
+
+    fn truncate(&self, table: &str) -> TruncateProps {
+        SQLImplementation::SQLite(self.clone()).truncate_initialization(table)
+    }
+}
+
+/// Runs a statement with a `RETURNING` clause and collects the rows it returns.
+pub(crate) fn returning_rows<P: rusqlite::Params>(
+    stmt: &mut rusqlite::Statement,
+    params: P,
+) -> Result<Vec<Vec<SQLDataTypes>>, Error> {
+    let column_size = stmt.column_count();
+    let declared_types = declared_types(stmt);
+    let mut rows = stmt.query(params)?;
+    let mut res = Vec::new();
+    while let Some(row) = rows.next()? {
+        let cells = (0..column_size)
+            .map(|idx| sqlite_cell(row, idx, &declared_types))
+            .collect::<Result<Vec<SQLDataTypes>, rusqlite::Error>>()?;
+        res.push(cells);
+    }
+    Ok(res)
 }
@@ -1,19 +1,38 @@
 use itertools::Itertools;
 
-use crate::{data_types::SQLDataTypes, statements::insert::DatatypeIndices};
+use crate::{
+    data_types::{SQLDataTypes, SQLInterval},
+    statements::insert::DatatypeIndices,
+};
 
 pub(crate) fn get_dt_indices(data: &Vec<Vec<SQLDataTypes>>) -> DatatypeIndices {
     let mut is_varchar: Vec<usize> = Vec::new();
+    let mut is_json: Vec<usize> = Vec::new();
+    let mut is_decimal: Vec<usize> = Vec::new();
     let mut is_float: Vec<usize> = Vec::new();
     let mut is_int: Vec<usize> = Vec::new();
+    let mut is_bool: Vec<usize> = Vec::new();
+    let mut is_timestamptz: Vec<usize> = Vec::new();
+    let mut is_timestamp: Vec<usize> = Vec::new();
     let mut is_date: Vec<usize> = Vec::new();
+    let mut is_time: Vec<usize> = Vec::new();
+    let mut is_interval: Vec<usize> = Vec::new();
+    let mut is_interval_ym: Vec<usize> = Vec::new();
 
     if data.len() == 1 {
         match data[0][0] {
             SQLDataTypes::Varchar(_) => is_varchar.push(0),
             SQLDataTypes::Number(_) => is_int.push(0),
             SQLDataTypes::Float(_) => is_float.push(0),
+            SQLDataTypes::Decimal(_) => is_decimal.push(0),
             SQLDataTypes::Date(_) => is_date.push(0),
+            SQLDataTypes::Time(_) => is_time.push(0),
+            SQLDataTypes::Timestamp(_) => is_timestamp.push(0),
+            SQLDataTypes::TimestampTz(_) => is_timestamptz.push(0),
+            SQLDataTypes::Interval(SQLInterval::DayToSecond(_)) => is_interval.push(0),
+            SQLDataTypes::Interval(SQLInterval::YearToMonth { .. }) => is_interval_ym.push(0),
+            SQLDataTypes::Bool(_) => is_bool.push(0),
+            SQLDataTypes::Json(_) => is_json.push(0),
             SQLDataTypes::NULL => is_varchar.push(0),
         }
     } else {
@@ -24,7 +43,17 @@ pub(crate) fn get_dt_indices(data: &Vec<Vec<SQLDataTypes>>) -> DatatypeIndices {
                     SQLDataTypes::Varchar(_) => is_varchar.push(x_idx),
                     SQLDataTypes::Number(_) => is_int.push(x_idx),
                     SQLDataTypes::Float(_) => is_float.push(x_idx),
+                    SQLDataTypes::Decimal(_) => is_decimal.push(x_idx),
                     SQLDataTypes::Date(_) => is_date.push(x_idx),
+                    SQLDataTypes::Time(_) => is_time.push(x_idx),
+                    SQLDataTypes::Timestamp(_) => is_timestamp.push(x_idx),
+                    SQLDataTypes::TimestampTz(_) => is_timestamptz.push(x_idx),
+                    SQLDataTypes::Interval(SQLInterval::DayToSecond(_)) => is_interval.push(x_idx),
+                    SQLDataTypes::Interval(SQLInterval::YearToMonth { .. }) => {
+                        is_interval_ym.push(x_idx)
+                    }
+                    SQLDataTypes::Bool(_) => is_bool.push(x_idx),
+                    SQLDataTypes::Json(_) => is_json.push(x_idx),
                     SQLDataTypes::NULL => continue,
                 }
             }
@@ -33,51 +62,93 @@ pub(crate) fn get_dt_indices(data: &Vec<Vec<SQLDataTypes>>) -> DatatypeIndices {
 
     DatatypeIndices {
         is_varchar,
+        is_json,
+        is_decimal,
         is_float,
         is_int,
+        is_bool,
+        is_timestamptz,
+        is_timestamp,
         is_date,
+        is_time,
+        is_interval,
+        is_interval_ym,
     }
     .find_uniques()
 }
 
+/// Keeps each column index only in the first list it shows up in,
+/// so the lists need to be passed in from the most to the least dominant data type.
+pub(crate) fn keep_dominant<const N: usize>(lists: [Vec<usize>; N]) -> [Vec<usize>; N] {
+    let mut seen: Vec<usize> = Vec::new();
+    lists.map(|list| {
+        let list = list
+            .into_iter()
+            .unique()
+            .filter(|x_idx| !seen.contains(x_idx))
+            .collect::<Vec<usize>>();
+        seen.extend(list.iter());
+        list
+    })
+}
+
 impl DatatypeIndices {
-    pub(crate) fn find_uniques(mut self) -> Self {
-        let is_varchar = self.is_varchar.into_iter().unique().collect::<Vec<usize>>();
-        for x_idx in is_varchar.iter() {
-            if self.is_float.contains(x_idx) {
-                self.is_float.retain(|v| *v != *x_idx);
-            } else if self.is_int.contains(x_idx) {
-                self.is_int.retain(|v| *v != *x_idx);
-            } else if self.is_date.contains(x_idx) {
-                self.is_date.retain(|v| *v != *x_idx);
-            } else {
-                continue;
-            }
-        }
-        let is_float = self.is_float.into_iter().unique().collect::<Vec<usize>>();
-        for x_idx in is_float.iter() {
-            if self.is_int.contains(x_idx) {
-                self.is_int.retain(|v| *v != *x_idx);
-            } else if self.is_date.contains(x_idx) {
-                self.is_date.retain(|v| *v != *x_idx);
-            } else {
-                continue;
-            }
-        }
-        let is_int = self.is_int.into_iter().unique().collect::<Vec<usize>>();
-        for x_idx in is_int.iter() {
-            if self.is_date.contains(x_idx) {
-                self.is_date.retain(|v| *v != *x_idx);
-            } else {
-                continue;
-            }
-        }
-        let is_date = self.is_date.into_iter().unique().collect::<Vec<usize>>();
+    pub(crate) fn find_uniques(self) -> Self {
+        // weighted in order: VARCHAR2, JSON, DECIMAL, FLOAT, INT, BOOL, TIMESTAMPTZ, TIMESTAMP, DATE, TIME, INTERVAL, INTERVAL YEAR TO MONTH
+        // A column with both kinds of interval doesn't fit either one
+        let mixed_intervals = self
+            .is_interval
+            .iter()
+            .filter(|idx| self.is_interval_ym.contains(idx))
+            .copied()
+            .collect::<Vec<usize>>();
+        let [
+            is_varchar,
+            is_json,
+            is_decimal,
+            is_float,
+            is_int,
+            is_bool,
+            is_timestamptz,
+            is_timestamp,
+            is_date,
+            is_time,
+            is_interval,
+            is_interval_ym,
+        ] = keep_dominant([
+            [self.is_varchar, mixed_intervals].concat(),
+            self.is_json,
+            self.is_decimal,
+            self.is_float,
+            self.is_int,
+            self.is_bool,
+            self.is_timestamptz,
+            self.is_timestamp,
+            self.is_date,
+            self.is_time,
+            self.is_interval,
+            self.is_interval_ym,
+        ]);
         Self {
             is_varchar,
+            is_json,
+            is_decimal,
             is_float,
             is_int,
+            is_bool,
+            is_timestamptz,
+            is_timestamp,
             is_date,
+            is_time,
+            is_interval,
+            is_interval_ym,
         }
     }
 }
+
+pub(crate) fn fmt_oracle_interval(interval: &SQLInterval) -> String {
+    match interval {
+        SQLInterval::YearToMonth { .. } => format!("INTERVAL '{interval}' YEAR(9) TO MONTH"),
+        SQLInterval::DayToSecond(_) => format!("INTERVAL '{interval}' DAY(9) TO SECOND(9)"),
+    }
+}
@@ -2,7 +2,7 @@ use crate::{
     Error, SQLImplementation,
     statements::{
         alter::sql_implementations::{oracle::alter_oracle, sqlite::alter_sqlite},
-        create::CreateDataTypes,
+        create::sql_implementations::utils::fmt_data_type,
     },
 };
 
@@ -30,10 +30,19 @@ impl AlterTableBuilder for AlterTable {
     fn add(mut self, columns: Vec<AlterColumns>) -> Altered {
         let cols = columns
             .iter()
-            .map(|cols| alter_cols_fmt(cols))
+            .map(|cols| alter_cols_fmt(cols, &self.connect))
             .collect::<Vec<String>>();
-        let add = format!("ADD ({})", cols.join(", "));
-        self.query = format!("{} {add}", &self.query);
+        self.query = match self.connect {
+            SQLImplementation::Oracle(_) => format!("{} ADD ({})", &self.query, cols.join(", ")),
+            // SQLite only adds one column per statement, so they're added in one transaction to add all or none
+            SQLImplementation::SQLite(_) => format!(
+                "BEGIN; {}; COMMIT",
+                cols.iter()
+                    .map(|col| format!("{} ADD COLUMN {col}", &self.query))
+                    .collect::<Vec<String>>()
+                    .join("; ")
+            ),
+        };
         Altered {
             connect: self.connect,
             query: self.query,
@@ -43,7 +52,7 @@ impl AlterTableBuilder for AlterTable {
     fn modify(mut self, columns: Vec<AlterColumns>) -> Altered {
         let cols = columns
             .iter()
-            .map(|cols| alter_cols_fmt(cols))
+            .map(|cols| alter_cols_fmt(cols, &self.connect))
             .collect::<Vec<String>>();
         let modify = format!("MODIFY ({})", cols.join(", "));
         self.query = format!("{} {modify}", &self.query);
@@ -81,13 +90,8 @@ impl AlterTableBuilder for AlterTable {
     }
 }
 
-pub fn alter_cols_fmt(cols: &AlterColumns) -> String {
-    let data_type = match cols.data_type {
-        CreateDataTypes::VARCHAR(size) => format!("VARCHAR2 ({size})"),
-        CreateDataTypes::NUMBER => format!("NUMBER"),
-        CreateDataTypes::FLOAT => format!("FLOAT"),
-        CreateDataTypes::DATE => format!("DATE"),
-    };
+pub fn alter_cols_fmt(cols: &AlterColumns, connect: &SQLImplementation) -> String {
+    let data_type = fmt_data_type(&cols.data_type, connect);
     let mut res = format!("{} {data_type}", cols.name);
     res = if let Some(sql) = &cols.default {
         format!("{res} DEFAULT '{sql}'")
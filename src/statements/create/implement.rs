@@ -1,11 +1,24 @@
 use crate::{
     Error, SQLImplementation,
     statements::create::sql_implementations::{
-        oracle::oracle_build_create_table, sqlite::sqlite_build_create_table,
+        oracle::{
+            oracle_build_create_index, oracle_build_create_table, oracle_build_create_table_as,
+            oracle_build_create_view,
+        },
+        sqlite::{
+            sqlite_build_create_index, sqlite_build_create_table, sqlite_build_create_table_as,
+            sqlite_build_create_view,
+        },
+        utils::{fmt_create_table_columns, lossy_mappings},
     },
 };
 
-use super::{CreateColumns, CreateDataTypes, CreateProps, CreateTable, ModifyCreateTable};
+use super::{
+    CreateColumns, CreateDataTypes, CreateIndex, CreateIndexBuilder, CreateProps, CreateTable,
+    CreateTableAs, CreateView, CreateViewBuilder, ForeignKey, ModifyCreateTable, TableForeignKey,
+    TranslatedTable,
+};
+use crate::{data_types::ToSQLData, statements::select::SelectProps};
 
 impl CreateProps {
     pub fn table(self, table: &str, columns: Vec<CreateColumns>) -> CreateTable {
@@ -13,19 +26,171 @@ impl CreateProps {
             connect: self.connect,
             columns,
             table: table.to_string(),
+            primary_key: Vec::new(),
+            unique: Vec::new(),
+            foreign_keys: Vec::new(),
+        }
+    }
+
+    pub fn index(self, name: &str) -> CreateIndex {
+        CreateIndex {
+            connect: self.connect,
+            name: name.to_string(),
+            table: String::new(),
+            columns: Vec::new(),
+            unique: false,
+            if_not_exists: false,
+        }
+    }
+
+    /// Creates a view from a select query.
+    ///
+    /// ```no_run
+    /// let select = conn
+    ///     .select("regional_sales", vec![region, total])
+    ///     .where_not(region, WhereArg::NULL);
+    /// conn.create()
+    ///     .view("v_regional_sales", select)
+    ///     .or_replace()
+    ///     .build()?;
+    /// ```
+    pub fn view(self, name: &str, select: SelectProps) -> CreateView {
+        CreateView {
+            connect: self.connect,
+            name: name.to_string(),
+            select,
+            or_replace: false,
+        }
+    }
+
+    /// Creates a new table from the results of a select query.
+    ///
+    /// ```no_run
+    /// let select = conn
+    ///     .select("regional_sales", vec![Column::ALL("regional_sales".to_string())])
+    ///     .where_in(region, WhereArg::Values(vec!["North America"]));
+    /// conn.create()
+    ///     .table_as("north_american_sales", select)
+    ///     .build()?;
+    /// ```
+    pub fn table_as(self, table: &str, select: SelectProps) -> CreateTableAs {
+        CreateTableAs {
+            connect: self.connect,
+            table: table.to_string(),
+            select,
         }
     }
 }
 
-impl ModifyCreateTable for CreateTable {
-    fn add_column(&mut self, column: String, data_type: CreateDataTypes) -> Self {
-        self.columns.push(CreateColumns {
-            name: column,
+impl CreateTable {
+    /// Renders the CREATE TABLE query for another database, translating the types and constraints.
+    /// Columns that can't be represented exactly are listed in [`TranslatedTable::lossy_mappings`].
+    ///
+    /// ```no_run
+    /// let create_table = oracle_conn.table_ddl("regional_sales")?;
+    /// let translated = create_table.to_sql_for(SQLImplementation::SQLite(sqlite_conn));
+    /// for lossy in translated.lossy_mappings {
+    ///     println!("{}: {} -> {} ({})", lossy.column, lossy.from, lossy.to, lossy.reason);
+    /// }
+    /// ```
+    pub fn to_sql_for(&self, target: SQLImplementation) -> TranslatedTable {
+        let lossy_mappings = lossy_mappings(self, &self.connect, &target);
+        let mut create_table = self.clone();
+        create_table.connect = target;
+        TranslatedTable {
+            sql: fmt_create_table_columns(&create_table),
+            lossy_mappings,
+        }
+    }
+}
+
+impl CreateTableAs {
+    /// Builds the query.
+    pub fn build(self) -> Result<(), Error> {
+        match self.connect {
+            SQLImplementation::Oracle(_) => oracle_build_create_table_as(self),
+            SQLImplementation::SQLite(_) => sqlite_build_create_table_as(self),
+        }
+    }
+}
+
+impl CreateColumns {
+    pub fn new(name: &str, data_type: CreateDataTypes) -> Self {
+        Self {
+            name: name.to_string(),
             data_type,
+            primary_key: false,
+            not_null: false,
+            unique: false,
+            default: None,
+            check: None,
+            references: None,
+        }
+    }
+
+    pub fn primary_key(mut self) -> Self {
+        self.primary_key = true;
+        self
+    }
+
+    pub fn not_null(mut self) -> Self {
+        self.not_null = true;
+        self
+    }
+
+    pub fn unique(mut self) -> Self {
+        self.unique = true;
+        self
+    }
+
+    pub fn default<T: ToSQLData>(mut self, value: T) -> Self {
+        self.default = Some(value.to_sql_fmt());
+        self
+    }
+
+    pub fn check(mut self, condition: &str) -> Self {
+        self.check = Some(condition.to_string());
+        self
+    }
+
+    pub fn references(mut self, table: &str, column: &str) -> Self {
+        self.references = Some(ForeignKey {
+            table: table.to_string(),
+            column: column.to_string(),
         });
+        self
+    }
+}
+
+impl ModifyCreateTable for CreateTable {
+    fn add_column(&mut self, column: String, data_type: CreateDataTypes) -> Self {
+        self.columns.push(CreateColumns::new(&column, data_type));
         self.to_owned()
     }
 
+    fn primary_key(mut self, columns: &[&str]) -> Self {
+        self.primary_key = columns.iter().map(|col| col.to_string()).collect();
+        self
+    }
+
+    fn unique(mut self, columns: &[&str]) -> Self {
+        self.unique
+            .push(columns.iter().map(|col| col.to_string()).collect());
+        self
+    }
+
+    fn foreign_key(mut self, columns: &[&str], table: &str, referenced_columns: &[&str]) -> Self {
+        self.foreign_keys.push(TableForeignKey {
+            columns: columns.iter().map(|col| col.to_string()).collect(),
+            table: table.to_string(),
+            referenced_columns: referenced_columns
+                .iter()
+                .map(|col| col.to_string())
+                .collect(),
+        });
+        self
+    }
+
     fn build(self) -> Result<(), Error> {
         match self.connect {
             SQLImplementation::Oracle(_) => oracle_build_create_table(self),
@@ -33,3 +198,45 @@ impl ModifyCreateTable for CreateTable {
         }
     }
 }
+
+impl CreateIndexBuilder for CreateIndex {
+    fn on(mut self, table: &str, columns: &[&str]) -> Self {
+        self.table = table.to_string();
+        self.columns = columns.iter().map(|col| col.to_string()).collect();
+        self
+    }
+
+    fn unique(mut self) -> Self {
+        self.unique = true;
+        self
+    }
+
+    fn if_not_exists(mut self) -> Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    fn build(self) -> Result<(), Error> {
+        if self.table.is_empty() || self.columns.is_empty() {
+            return Err(Error::NoIndexColumns);
+        }
+        match self.connect {
+            SQLImplementation::Oracle(_) => oracle_build_create_index(self),
+            SQLImplementation::SQLite(_) => sqlite_build_create_index(self),
+        }
+    }
+}
+
+impl CreateViewBuilder for CreateView {
+    fn or_replace(mut self) -> Self {
+        self.or_replace = true;
+        self
+    }
+
+    fn build(self) -> Result<(), Error> {
+        match self.connect {
+            SQLImplementation::Oracle(_) => oracle_build_create_view(self),
+            SQLImplementation::SQLite(_) => sqlite_build_create_view(self),
+        }
+    }
+}
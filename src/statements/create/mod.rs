@@ -1,4 +1,4 @@
-use crate::{Error, SQLImplementation};
+use crate::{Error, SQLImplementation, data_types::SQLDataTypes, statements::select::SelectProps};
 
 pub mod implement;
 pub mod sql_implementations;
@@ -8,6 +8,57 @@ pub struct CreateTable {
     pub connect: SQLImplementation,
     pub columns: Vec<CreateColumns>,
     pub table: String,
+    /// Table-level (composite) primary key.
+    pub primary_key: Vec<String>,
+    /// Table-level (composite) unique constraints.
+    pub unique: Vec<Vec<String>>,
+    /// Table-level (composite) foreign keys.
+    pub foreign_keys: Vec<TableForeignKey>,
+}
+
+/// Translated CREATE TABLE query returned by [`CreateTable::to_sql_for`].
+#[derive(Debug, Clone)]
+pub struct TranslatedTable {
+    pub sql: String,
+    /// Columns that can't be represented exactly in the target database.
+    pub lossy_mappings: Vec<LossyMapping>,
+}
+
+#[derive(Debug, Clone)]
+pub struct LossyMapping {
+    pub column: String,
+    /// The type in the source database.
+    pub from: String,
+    /// The type it becomes in the target database.
+    pub to: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateIndex {
+    pub connect: SQLImplementation,
+    pub name: String,
+    pub table: String,
+    /// Column names or expressions, like `UPPER(email)`.
+    pub columns: Vec<String>,
+    pub unique: bool,
+    pub if_not_exists: bool,
+}
+
+#[derive(Debug)]
+pub struct CreateView {
+    pub connect: SQLImplementation,
+    pub name: String,
+    pub select: SelectProps,
+    pub or_replace: bool,
+}
+
+/// CREATE TABLE ... AS SELECT ...
+#[derive(Debug)]
+pub struct CreateTableAs {
+    pub connect: SQLImplementation,
+    pub table: String,
+    pub select: SelectProps,
 }
 
 #[derive(Debug)]
@@ -15,24 +66,121 @@ pub struct CreateProps {
     pub connect: SQLImplementation,
 }
 
+/// A column in a CREATE TABLE query.
+/// Use [`CreateColumns::new`] and chain the constraints you need onto it.
+///
+/// ```no_run
+/// let id = CreateColumns::new("id", CreateDataTypes::NUMBER(0, 0)).primary_key();
+/// let region_id = CreateColumns::new("region_id", CreateDataTypes::NUMBER(0, 0))
+///     .not_null()
+///     .references("regions", "id");
+/// let status = CreateColumns::new("status", CreateDataTypes::VARCHAR(10))
+///     .default("OPEN")
+///     .check("status IN ('OPEN', 'CLOSED')");
+/// ```
 #[derive(Debug, Clone)]
 pub struct CreateColumns {
     pub name: String,
     pub data_type: CreateDataTypes,
+    pub primary_key: bool,
+    pub not_null: bool,
+    pub unique: bool,
+    pub default: Option<SQLDataTypes>,
+    /// The condition inside of `CHECK (...)`.
+    pub check: Option<String>,
+    pub references: Option<ForeignKey>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ForeignKey {
+    pub table: String,
+    pub column: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct TableForeignKey {
+    pub columns: Vec<String>,
+    pub table: String,
+    pub referenced_columns: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
 pub enum CreateDataTypes {
     VARCHAR(usize),
-    NUMBER,
+    /// Precision and scale. A precision of `0` is an unconstrained `NUMBER`.
+    NUMBER(usize, usize),
     FLOAT,
     DATE,
+    /// Oracle doesn't have a TIME type, so it's created as a `VARCHAR2(18)` there.
+    TIME,
+    TIMESTAMP,
+    TIMESTAMPTZ,
+    /// An `INTERVAL DAY TO SECOND`.
+    INTERVAL,
+    /// An `INTERVAL YEAR TO MONTH`.
+    INTERVALYM,
+    /// Created as a `NUMBER(1)` on Oracle and an `INTEGER` on SQLite, since `BOOLEAN` is only available from Oracle 23.
+    BOOL,
+    /// Created as a `JSON` column on Oracle (21c and up) and on SQLite, where it's stored as text.
+    JSON,
+    /// Text longer than a `VARCHAR2` can hold. Created as a `CLOB` on Oracle and as `TEXT` on SQLite.
+    CLOB,
 }
 
 pub trait ModifyCreateTable {
     /// Adds a column to the CREATE TABLE query.
     fn add_column(&mut self, column: String, data_type: CreateDataTypes) -> Self;
 
+    /// Adds a table-level primary key. Use this for composite keys, otherwise [`CreateColumns::primary_key`] works.
+    ///
+    /// ```no_run
+    /// conn.create()
+    ///     .table("order_lines", columns)
+    ///     .primary_key(&["order_id", "line_no"])
+    ///     .build()?;
+    /// ```
+    fn primary_key(self, columns: &[&str]) -> Self;
+
+    /// Adds a table-level unique constraint across the columns. Can be called more than once.
+    fn unique(self, columns: &[&str]) -> Self;
+
+    /// Adds a table-level foreign key. Use this for composite keys, otherwise [`CreateColumns::references`] works.
+    /// Can be called more than once.
+    fn foreign_key(self, columns: &[&str], table: &str, referenced_columns: &[&str]) -> Self;
+
+    /// Builds the query.
+    fn build(self) -> Result<(), Error>;
+}
+
+pub trait CreateIndexBuilder {
+    /// Sets the table and the columns the index is on.
+    /// Expressions can be passed in as well to create a function-based index.
+    ///
+    /// ```no_run
+    /// conn.create()
+    ///     .index("idx_employees_email")
+    ///     .on("employees", &["UPPER(email)"])
+    ///     .unique()
+    ///     .build()?;
+    /// ```
+    fn on(self, table: &str, columns: &[&str]) -> Self;
+
+    /// Creates a `UNIQUE` index.
+    fn unique(self) -> Self;
+
+    /// Doesn't error if an index with the same name already exists.
+    /// Uses `IF NOT EXISTS` on SQLite, on Oracle the "name is already used" error is ignored.
+    fn if_not_exists(self) -> Self;
+
+    /// Builds the query.
+    fn build(self) -> Result<(), Error>;
+}
+
+pub trait CreateViewBuilder {
+    /// Replaces the view if it already exists.
+    /// SQLite doesn't support `OR REPLACE`, so the view is dropped and created again in the same transaction.
+    fn or_replace(self) -> Self;
+
     /// Builds the query.
     fn build(self) -> Result<(), Error>;
 }
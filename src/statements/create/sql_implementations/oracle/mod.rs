@@ -1,6 +1,9 @@
 use crate::{
     Error, SQLImplementation,
-    statements::create::{CreateTable, sql_implementations::utils::fmt_create_table_columns},
+    statements::create::{
+        CreateIndex, CreateTable, CreateTableAs, CreateView,
+        sql_implementations::utils::{fmt_create_index, fmt_create_table_columns},
+    },
 };
 
 pub(crate) fn oracle_build_create_table(create_table: CreateTable) -> Result<(), Error> {
@@ -20,3 +23,69 @@ pub(crate) fn oracle_build_create_table(create_table: CreateTable) -> Result<(),
     conn.commit()?;
     Ok(())
 }
+
+pub(crate) fn oracle_build_create_index(create_index: CreateIndex) -> Result<(), Error> {
+    let conn_info = match &create_index.connect {
+        SQLImplementation::Oracle(oracle_connect) => oracle_connect,
+        SQLImplementation::SQLite(_) => return Err(Error::SQLVariationError),
+    };
+
+    let sql = fmt_create_index(&create_index);
+    let conn: oracle::Connection = oracle::Connection::connect(
+        &conn_info.username,
+        &conn_info.password,
+        &conn_info.connection_string,
+    )?;
+    match conn.execute(&sql, &[]) {
+        Ok(_) => Ok(()),
+        // ORA-00955: name is already used by an existing object
+        Err(e) if create_index.if_not_exists && e.oci_code() == Some(955) => Ok(()),
+        Err(e) => Err(Error::OracleError(e)),
+    }
+}
+
+pub(crate) fn oracle_build_create_view(create_view: CreateView) -> Result<(), Error> {
+    let conn_info = match (&create_view.connect, &create_view.select.connect) {
+        (SQLImplementation::Oracle(oracle_connect), SQLImplementation::Oracle(_)) => oracle_connect,
+        _ => return Err(Error::SQLVariationError),
+    };
+
+    let or_replace = if create_view.or_replace {
+        "OR REPLACE "
+    } else {
+        ""
+    };
+    let sql = format!(
+        "CREATE {or_replace}VIEW {} AS {}",
+        create_view.name,
+        create_view.select.to_sql()?
+    );
+    let conn: oracle::Connection = oracle::Connection::connect(
+        &conn_info.username,
+        &conn_info.password,
+        &conn_info.connection_string,
+    )?;
+    conn.execute(&sql, &[])?;
+    Ok(())
+}
+
+pub(crate) fn oracle_build_create_table_as(create_table: CreateTableAs) -> Result<(), Error> {
+    let conn_info = match (&create_table.connect, &create_table.select.connect) {
+        (SQLImplementation::Oracle(oracle_connect), SQLImplementation::Oracle(_)) => oracle_connect,
+        _ => return Err(Error::SQLVariationError),
+    };
+
+    let sql = format!(
+        "CREATE TABLE {} AS {}",
+        create_table.table,
+        create_table.select.to_sql()?
+    );
+    let conn: oracle::Connection = oracle::Connection::connect(
+        &conn_info.username,
+        &conn_info.password,
+        &conn_info.connection_string,
+    )?;
+    conn.execute(&sql, &[])?;
+    conn.commit()?;
+    Ok(())
+}
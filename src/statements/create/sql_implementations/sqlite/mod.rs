@@ -1,6 +1,9 @@
 use crate::{
     Error, SQLImplementation,
-    statements::create::{CreateTable, sql_implementations::utils::fmt_create_table_columns},
+    statements::create::{
+        CreateIndex, CreateTable, CreateTableAs, CreateView,
+        sql_implementations::utils::{fmt_create_index, fmt_create_table_columns},
+    },
 };
 
 pub(crate) fn sqlite_build_create_table(create_table: CreateTable) -> Result<(), Error> {
@@ -14,3 +17,56 @@ pub(crate) fn sqlite_build_create_table(create_table: CreateTable) -> Result<(),
     conn.execute(&sql, ())?;
     Ok(())
 }
+
+pub(crate) fn sqlite_build_create_index(create_index: CreateIndex) -> Result<(), Error> {
+    let conn_info = match &create_index.connect {
+        SQLImplementation::Oracle(_) => return Err(Error::SQLVariationError),
+        SQLImplementation::SQLite(connect) => connect,
+    };
+
+    let sql = fmt_create_index(&create_index);
+    let conn = conn_info.initialize_connection()?;
+    conn.execute(&sql, ())?;
+    Ok(())
+}
+
+pub(crate) fn sqlite_build_create_view(create_view: CreateView) -> Result<(), Error> {
+    let conn_info = match (&create_view.connect, &create_view.select.connect) {
+        (SQLImplementation::SQLite(connect), SQLImplementation::SQLite(_)) => connect,
+        _ => return Err(Error::SQLVariationError),
+    };
+
+    let create = format!(
+        "CREATE VIEW {} AS {}",
+        create_view.name,
+        create_view.select.to_sql()?
+    );
+    // SQLite doesn't have CREATE OR REPLACE VIEW
+    let sql = if create_view.or_replace {
+        format!(
+            "BEGIN; DROP VIEW IF EXISTS {}; {create}; COMMIT;",
+            create_view.name
+        )
+    } else {
+        create
+    };
+    let conn = conn_info.initialize_connection()?;
+    conn.execute_batch(&sql)?;
+    Ok(())
+}
+
+pub(crate) fn sqlite_build_create_table_as(create_table: CreateTableAs) -> Result<(), Error> {
+    let conn_info = match (&create_table.connect, &create_table.select.connect) {
+        (SQLImplementation::SQLite(connect), SQLImplementation::SQLite(_)) => connect,
+        _ => return Err(Error::SQLVariationError),
+    };
+
+    let sql = format!(
+        "CREATE TABLE {} AS {}",
+        create_table.table,
+        create_table.select.to_sql()?
+    );
+    let conn = conn_info.initialize_connection()?;
+    conn.execute(&sql, ())?;
+    Ok(())
+}
@@ -1,25 +1,220 @@
-use crate::statements::create::{CreateDataTypes, CreateTable};
+use crate::{
+    SQLImplementation,
+    data_types::SQLDataTypes,
+    sql_implementations::utils::fmt_oracle_interval,
+    statements::create::{CreateColumns, CreateDataTypes, CreateIndex, CreateTable, LossyMapping},
+};
+
+// Longest VARCHAR2 Oracle takes without MAX_STRING_SIZE = EXTENDED
+pub(crate) const ORACLE_VARCHAR_LIMIT: usize = 4000;
+
+pub(crate) fn fmt_number(precision: usize, scale: usize) -> String {
+    match (precision, scale) {
+        (0, _) => String::from("NUMBER"),
+        (precision, 0) => format!("NUMBER({precision})"),
+        (precision, scale) => format!("NUMBER({precision}, {scale})"),
+    }
+}
+
+pub(crate) fn fmt_data_type(data_type: &CreateDataTypes, connect: &SQLImplementation) -> String {
+    match connect {
+        SQLImplementation::Oracle(_) => match *data_type {
+            CreateDataTypes::VARCHAR(num) if num > ORACLE_VARCHAR_LIMIT => String::from("CLOB"),
+            CreateDataTypes::VARCHAR(num) => format!("VARCHAR2({})", num.max(1)),
+            CreateDataTypes::NUMBER(precision, scale) => fmt_number(precision, scale),
+            CreateDataTypes::FLOAT => String::from("FLOAT"),
+            CreateDataTypes::DATE => String::from("DATE"),
+            CreateDataTypes::TIME => String::from("VARCHAR2(18)"),
+            CreateDataTypes::TIMESTAMP => String::from("TIMESTAMP"),
+            CreateDataTypes::TIMESTAMPTZ => String::from("TIMESTAMP WITH TIME ZONE"),
+            CreateDataTypes::INTERVAL => String::from("INTERVAL DAY(9) TO SECOND(9)"),
+            CreateDataTypes::INTERVALYM => String::from("INTERVAL YEAR(9) TO MONTH"),
+            CreateDataTypes::BOOL => String::from("NUMBER(1)"),
+            CreateDataTypes::JSON => String::from("JSON"),
+            CreateDataTypes::CLOB => String::from("CLOB"),
+        },
+        SQLImplementation::SQLite(_) => match *data_type {
+            CreateDataTypes::VARCHAR(num) => format!("VARCHAR2({})", num.max(1)),
+            // SQLite would turn these into a REAL, so exact decimals are stored as text
+            CreateDataTypes::NUMBER(precision, scale) if scale > 0 || precision > 18 => {
+                String::from("TEXT")
+            }
+            CreateDataTypes::NUMBER(precision, scale) => fmt_number(precision, scale),
+            CreateDataTypes::FLOAT => String::from("FLOAT"),
+            CreateDataTypes::DATE => String::from("DATE"),
+            CreateDataTypes::TIME => String::from("TIME"),
+            CreateDataTypes::TIMESTAMP => String::from("TIMESTAMP"),
+            CreateDataTypes::TIMESTAMPTZ => String::from("TIMESTAMPTZ"),
+            CreateDataTypes::INTERVAL => String::from("INTERVAL"),
+            CreateDataTypes::INTERVALYM => String::from("INTERVALYM"),
+            CreateDataTypes::BOOL => String::from("INTEGER"),
+            CreateDataTypes::JSON => String::from("JSON"),
+            CreateDataTypes::CLOB => String::from("TEXT"),
+        },
+    }
+}
+
+pub(crate) fn fmt_default(value: &SQLDataTypes, connect: &SQLImplementation) -> String {
+    match connect {
+        SQLImplementation::Oracle(_) => match value {
+            SQLDataTypes::Varchar(val) => format!("'{}'", val.replace('\'', "''")),
+            SQLDataTypes::Number(val) => val.to_string(),
+            SQLDataTypes::Float(val) => val.to_string(),
+            SQLDataTypes::Decimal(val) => val.to_plain_string(),
+            SQLDataTypes::Date(val) => format!("DATE '{val}'"),
+            SQLDataTypes::Time(val) => format!("'{val}'"),
+            SQLDataTypes::Timestamp(val) => format!("TIMESTAMP '{val}'"),
+            SQLDataTypes::TimestampTz(val) => {
+                format!("TIMESTAMP '{}'", val.format("%Y-%m-%d %H:%M:%S%.f %:z"))
+            }
+            SQLDataTypes::Interval(val) => fmt_oracle_interval(val),
+            SQLDataTypes::Bool(val) => i64::from(*val).to_string(),
+            SQLDataTypes::Json(val) => format!("'{}'", val.to_string().replace('\'', "''")),
+            SQLDataTypes::NULL => String::from("NULL"),
+        },
+        SQLImplementation::SQLite(_) => match value {
+            SQLDataTypes::Number(val) => val.to_string(),
+            SQLDataTypes::Float(val) => val.to_string(),
+            SQLDataTypes::Bool(val) => i64::from(*val).to_string(),
+            SQLDataTypes::TimestampTz(val) => format!("'{}'", val.to_rfc3339()),
+            SQLDataTypes::NULL => String::from("NULL"),
+            // everything else is stored as text
+            other => format!("'{}'", other.to_string().replace('\'', "''")),
+        },
+    }
+}
+
+fn fmt_column(column: &CreateColumns, connect: &SQLImplementation) -> String {
+    let mut res = format!(
+        "{} {}",
+        column.name,
+        fmt_data_type(&column.data_type, connect)
+    );
+    if let Some(value) = &column.default {
+        res = format!("{res} DEFAULT {}", fmt_default(value, connect));
+    }
+    if column.not_null {
+        res = format!("{res} NOT NULL");
+    }
+    if column.primary_key {
+        res = format!("{res} PRIMARY KEY");
+    }
+    if column.unique {
+        res = format!("{res} UNIQUE");
+    }
+    if let Some(condition) = &column.check {
+        res = format!("{res} CHECK ({condition})");
+    }
+    if let Some(foreign_key) = &column.references {
+        res = format!(
+            "{res} REFERENCES {}({})",
+            foreign_key.table, foreign_key.column
+        );
+    }
+    res
+}
 
 pub(crate) fn fmt_create_table_columns(create_table: &CreateTable) -> String {
-    let cols_and_data_types = create_table
+    let mut definitions = create_table
         .columns
         .iter()
-        .map(|col_props| match col_props.data_type {
-            CreateDataTypes::VARCHAR(mut num) => {
-                if num == 0 {
-                    num = 1
-                }
-                format!("{} VARCHAR2({})", &col_props.name, num)
-            }
-            CreateDataTypes::NUMBER => format!("{} NUMBER", &col_props.name),
-            CreateDataTypes::FLOAT => format!("{} FLOAT", &col_props.name),
-            CreateDataTypes::DATE => format!("{} DATE", &col_props.name),
-        })
-        .collect::<Vec<String>>()
-        .join(", ");
+        .map(|column| fmt_column(column, &create_table.connect))
+        .collect::<Vec<String>>();
+    if !create_table.primary_key.is_empty() {
+        definitions.push(format!(
+            "PRIMARY KEY ({})",
+            create_table.primary_key.join(", ")
+        ));
+    }
+    create_table.unique.iter().for_each(|columns| {
+        definitions.push(format!("UNIQUE ({})", columns.join(", ")));
+    });
+    create_table.foreign_keys.iter().for_each(|foreign_key| {
+        definitions.push(format!(
+            "FOREIGN KEY ({}) REFERENCES {}({})",
+            foreign_key.columns.join(", "),
+            foreign_key.table,
+            foreign_key.referenced_columns.join(", ")
+        ));
+    });
 
     format!(
         "CREATE TABLE {} ({})",
-        create_table.table, cols_and_data_types
+        create_table.table,
+        definitions.join(", ")
+    )
+}
+
+pub(crate) fn fmt_create_index(create_index: &CreateIndex) -> String {
+    let unique = if create_index.unique { "UNIQUE " } else { "" };
+    // Oracle doesn't support IF NOT EXISTS until 23, so it's handled when the query is executed instead
+    let if_not_exists = match (&create_index.connect, create_index.if_not_exists) {
+        (SQLImplementation::SQLite(_), true) => "IF NOT EXISTS ",
+        _ => "",
+    };
+    format!(
+        "CREATE {unique}INDEX {if_not_exists}{} ON {} ({})",
+        create_index.name,
+        create_index.table,
+        create_index.columns.join(", ")
     )
 }
+
+/// Finds the columns that can't be represented exactly when moving a table from `source` to `target`.
+pub(crate) fn lossy_mappings(
+    create_table: &CreateTable,
+    source: &SQLImplementation,
+    target: &SQLImplementation,
+) -> Vec<LossyMapping> {
+    create_table
+        .columns
+        .iter()
+        .filter_map(|column| {
+            let reason = match (source, target, &column.data_type) {
+                (SQLImplementation::Oracle(_), SQLImplementation::SQLite(_), data_type) => {
+                    match data_type {
+                        CreateDataTypes::NUMBER(precision, scale)
+                            if *scale > 0 || *precision > 18 =>
+                        {
+                            "stored as TEXT so the value stays exact, but loses numeric affinity"
+                        }
+                        CreateDataTypes::DATE
+                        | CreateDataTypes::TIME
+                        | CreateDataTypes::TIMESTAMP
+                        | CreateDataTypes::TIMESTAMPTZ
+                        | CreateDataTypes::INTERVAL
+                        | CreateDataTypes::INTERVALYM => {
+                            "SQLite has no date or time types, values are stored as text"
+                        }
+                        CreateDataTypes::VARCHAR(_) => "SQLite doesn't enforce the length",
+                        CreateDataTypes::JSON => "SQLite doesn't validate JSON",
+                        _ => return None,
+                    }
+                }
+                (SQLImplementation::SQLite(_), SQLImplementation::Oracle(_), data_type) => {
+                    match data_type {
+                        CreateDataTypes::TIME => {
+                            "Oracle has no TIME type, values are stored as text"
+                        }
+                        CreateDataTypes::BOOL => "Oracle doesn't have BOOLEAN before 23",
+                        CreateDataTypes::JSON => "JSON columns need Oracle 21c or later",
+                        CreateDataTypes::VARCHAR(size) if *size > ORACLE_VARCHAR_LIMIT => {
+                            "longer than the 4000 byte VARCHAR2 limit, so it's created as a CLOB"
+                        }
+                        CreateDataTypes::CLOB => {
+                            "SQLite text has no length limit, so it's created as a CLOB, which can't be compared or indexed"
+                        }
+                        _ => return None,
+                    }
+                }
+                _ => return None,
+            };
+            Some(LossyMapping {
+                column: column.name.to_string(),
+                from: fmt_data_type(&column.data_type, source),
+                to: fmt_data_type(&column.data_type, target),
+                reason: reason.to_string(),
+            })
+        })
+        .collect()
+}
@@ -1,9 +1,13 @@
 use crate::{
     Error, SQLImplementation,
-    statements::delete::sql_implementations::{oracle::oracle_build_delete, sqlite::sqlite_delete},
+    data_types::SQLDataTypes,
+    statements::delete::sql_implementations::{
+        oracle::{oracle_build_delete, oracle_build_delete_returning},
+        sqlite::{sqlite_delete, sqlite_delete_returning},
+    },
 };
 
-use super::{DeleteBuilder, DeleteProps};
+use super::{DeleteBuilder, DeleteProps, DeleteReturning};
 
 impl DeleteBuilder for DeleteProps {
     fn build(self) -> Result<(), Error> {
@@ -12,4 +16,21 @@ impl DeleteBuilder for DeleteProps {
             SQLImplementation::SQLite(_) => sqlite_delete(self),
         }
     }
+
+    fn returning(self, columns: &[&str]) -> DeleteReturning {
+        DeleteReturning {
+            delete_props: self,
+            columns: columns.iter().map(|col| col.to_string()).collect(),
+        }
+    }
+}
+
+impl DeleteReturning {
+    /// Builds the query and returns a row of the returning columns for each deleted row.
+    pub fn build(self) -> Result<Vec<Vec<SQLDataTypes>>, Error> {
+        match self.delete_props.connect {
+            SQLImplementation::Oracle(_) => oracle_build_delete_returning(self),
+            SQLImplementation::SQLite(_) => sqlite_delete_returning(self),
+        }
+    }
 }
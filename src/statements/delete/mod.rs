@@ -10,7 +10,23 @@ pub struct DeleteProps {
     pub clause: Option<String>,
 }
 
+/// A delete that returns columns of the deleted rows, created with [`returning`](DeleteBuilder::returning).
+#[derive(Debug)]
+pub struct DeleteReturning {
+    pub delete_props: DeleteProps,
+    pub columns: Vec<String>,
+}
+
 pub trait DeleteBuilder {
     /// Builds the DELETE query.
     fn build(self) -> Result<(), Error>;
+
+    /// Returns these columns for every deleted row when the [`DeleteReturning`] is built.
+    /// ```no_run
+    /// let deleted = conn.delete("orders")
+    ///     .where_in(&status, WhereArg::Values(vec!["cancelled".to_sql_fmt()]))
+    ///     .returning(&["order_id"])
+    ///     .build()?;
+    /// ```
+    fn returning(self, columns: &[&str]) -> DeleteReturning;
 }
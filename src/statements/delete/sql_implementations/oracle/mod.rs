@@ -1,4 +1,9 @@
-use crate::{Error, SQLImplementation, statements::delete::DeleteProps};
+use crate::{
+    Error, SQLImplementation,
+    data_types::SQLDataTypes,
+    sql_implementations::oracle::{fmt_returning_into, returned_rows, returning_binds},
+    statements::delete::{DeleteProps, DeleteReturning},
+};
 
 pub fn oracle_build_delete(props: DeleteProps) -> Result<(), Error> {
     let conn_info = match props.connect {
@@ -20,3 +25,38 @@ pub fn oracle_build_delete(props: DeleteProps) -> Result<(), Error> {
     conn.commit()?;
     Ok(())
 }
+
+pub(crate) fn oracle_build_delete_returning(
+    delete_returning: DeleteReturning,
+) -> Result<Vec<Vec<SQLDataTypes>>, Error> {
+    let props = delete_returning.delete_props;
+    let conn_info = match &props.connect {
+        SQLImplementation::Oracle(oracle_connect) => oracle_connect,
+        SQLImplementation::SQLite(_) => return Err(Error::SQLVariationError),
+    };
+
+    let sql = match &props.clause {
+        Some(filters) => format!("DELETE FROM {} WHERE {}", &props.table, filters),
+        None => format!("DELETE FROM {}", &props.table),
+    };
+    let sql = format!(
+        "{}{}",
+        sql,
+        fmt_returning_into(&delete_returning.columns, 0)
+    );
+    let binds = returning_binds(conn_info, &props.table, &delete_returning.columns)?;
+    let params = binds
+        .iter()
+        .map(|bind| bind as &dyn oracle::sql_type::ToSql)
+        .collect::<Vec<&dyn oracle::sql_type::ToSql>>();
+
+    let conn: oracle::Connection = oracle::Connection::connect(
+        &conn_info.username,
+        &conn_info.password,
+        &conn_info.connection_string,
+    )?;
+    let stmt = conn.execute(&sql, &params)?;
+    let res = returned_rows(&stmt, 0, delete_returning.columns.len())?;
+    conn.commit()?;
+    Ok(res)
+}
@@ -1,4 +1,9 @@
-use crate::{Error, SQLImplementation, statements::delete::DeleteProps};
+use crate::{
+    Error, SQLImplementation,
+    data_types::SQLDataTypes,
+    sql_implementations::sqlite::returning_rows,
+    statements::delete::{DeleteProps, DeleteReturning},
+};
 
 pub fn sqlite_delete(props: DeleteProps) -> Result<(), Error> {
     let conn_info = match &props.connect {
@@ -14,3 +19,26 @@ pub fn sqlite_delete(props: DeleteProps) -> Result<(), Error> {
     conn.execute(&query, [])?;
     Ok(())
 }
+
+pub(crate) fn sqlite_delete_returning(
+    delete_returning: DeleteReturning,
+) -> Result<Vec<Vec<SQLDataTypes>>, Error> {
+    let props = delete_returning.delete_props;
+    let conn_info = match &props.connect {
+        SQLImplementation::Oracle(_) => return Err(Error::SQLVariationError),
+        SQLImplementation::SQLite(connect) => connect,
+    };
+    let conn = conn_info.initialize_connection()?;
+
+    let query = match props.clause {
+        Some(filters) => format!("DELETE FROM {} WHERE {}", &props.table, filters),
+        None => format!("DELETE FROM {}", &props.table),
+    };
+    let query = format!(
+        "{} RETURNING {}",
+        query,
+        delete_returning.columns.join(", ")
+    );
+    let mut stmt = conn.prepare(&query)?;
+    returning_rows(&mut stmt, [])
+}
@@ -0,0 +1,119 @@
+use crate::{
+    Error, SQLImplementation,
+    statements::drop::sql_implementations::{
+        oracle::{oracle_build_drop_index, oracle_build_drop_table, oracle_build_drop_view},
+        sqlite::{sqlite_build_drop_index, sqlite_build_drop_table, sqlite_build_drop_view},
+    },
+};
+
+use super::{DropBuilder, DropIndex, DropProps, DropTable, DropView};
+
+impl DropProps {
+    /// Drops a table.
+    ///
+    /// ```no_run
+    /// conn.drop()
+    ///     .table("regional_sales")
+    ///     .if_exists()
+    ///     .cascade_constraints()
+    ///     .purge()
+    ///     .build()?;
+    /// ```
+    pub fn table(self, name: &str) -> DropTable {
+        DropTable {
+            connect: self.connect,
+            name: name.to_string(),
+            if_exists: false,
+            cascade_constraints: false,
+            purge: false,
+        }
+    }
+
+    /// Drops an index.
+    ///
+    /// ```no_run
+    /// conn.drop()
+    ///     .index("idx_employees_email")
+    ///     .if_exists()
+    ///     .build()?;
+    /// ```
+    pub fn index(self, name: &str) -> DropIndex {
+        DropIndex {
+            connect: self.connect,
+            name: name.to_string(),
+            if_exists: false,
+        }
+    }
+
+    /// Drops a view.
+    ///
+    /// ```no_run
+    /// conn.drop()
+    ///     .view("v_regional_sales")
+    ///     .if_exists()
+    ///     .build()?;
+    /// ```
+    pub fn view(self, name: &str) -> DropView {
+        DropView {
+            connect: self.connect,
+            name: name.to_string(),
+            if_exists: false,
+        }
+    }
+}
+
+impl DropTable {
+    /// Adds `CASCADE CONSTRAINTS` on Oracle. Does nothing on SQLite.
+    pub fn cascade_constraints(mut self) -> Self {
+        self.cascade_constraints = true;
+        self
+    }
+
+    /// Adds `PURGE` on Oracle. Does nothing on SQLite.
+    pub fn purge(mut self) -> Self {
+        self.purge = true;
+        self
+    }
+}
+
+impl DropBuilder for DropTable {
+    fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+
+    fn build(self) -> Result<(), Error> {
+        match self.connect {
+            SQLImplementation::Oracle(_) => oracle_build_drop_table(self),
+            SQLImplementation::SQLite(_) => sqlite_build_drop_table(self),
+        }
+    }
+}
+
+impl DropBuilder for DropIndex {
+    fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+
+    fn build(self) -> Result<(), Error> {
+        match self.connect {
+            SQLImplementation::Oracle(_) => oracle_build_drop_index(self),
+            SQLImplementation::SQLite(_) => sqlite_build_drop_index(self),
+        }
+    }
+}
+
+impl DropBuilder for DropView {
+    fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+
+    fn build(self) -> Result<(), Error> {
+        match self.connect {
+            SQLImplementation::Oracle(_) => oracle_build_drop_view(self),
+            SQLImplementation::SQLite(_) => sqlite_build_drop_view(self),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{Error, SQLImplementation};
+
+pub mod implement;
+pub mod sql_implementations;
+
+#[derive(Debug)]
+pub struct DropProps {
+    pub connect: SQLImplementation,
+}
+
+#[derive(Debug)]
+pub struct DropTable {
+    pub connect: SQLImplementation,
+    pub name: String,
+    pub if_exists: bool,
+    /// Oracle only: also drops the foreign keys in other tables that reference this table.
+    pub cascade_constraints: bool,
+    /// Oracle only: skips the recycle bin.
+    pub purge: bool,
+}
+
+#[derive(Debug)]
+pub struct DropIndex {
+    pub connect: SQLImplementation,
+    pub name: String,
+    pub if_exists: bool,
+}
+
+#[derive(Debug)]
+pub struct DropView {
+    pub connect: SQLImplementation,
+    pub name: String,
+    pub if_exists: bool,
+}
+
+pub trait DropBuilder {
+    /// Doesn't error if the object doesn't exist.
+    /// Uses `IF EXISTS` on SQLite, on Oracle the "does not exist" error is ignored.
+    fn if_exists(self) -> Self;
+
+    /// Builds the query.
+    fn build(self) -> Result<(), Error>;
+}
@@ -0,0 +1,63 @@
+use crate::{
+    Error, SQLImplementation,
+    sql_implementations::OracleConnect,
+    statements::drop::{DropIndex, DropTable, DropView},
+};
+
+pub(crate) fn oracle_build_drop_table(drop_table: DropTable) -> Result<(), Error> {
+    let conn_info = match &drop_table.connect {
+        SQLImplementation::Oracle(oracle_connect) => oracle_connect,
+        SQLImplementation::SQLite(_) => return Err(Error::SQLVariationError),
+    };
+
+    let mut sql = format!("DROP TABLE {}", drop_table.name);
+    if drop_table.cascade_constraints {
+        sql = format!("{sql} CASCADE CONSTRAINTS");
+    }
+    if drop_table.purge {
+        sql = format!("{sql} PURGE");
+    }
+    // ORA-00942: table or view does not exist
+    execute_drop(conn_info, &sql, drop_table.if_exists, 942)
+}
+
+pub(crate) fn oracle_build_drop_index(drop_index: DropIndex) -> Result<(), Error> {
+    let conn_info = match &drop_index.connect {
+        SQLImplementation::Oracle(oracle_connect) => oracle_connect,
+        SQLImplementation::SQLite(_) => return Err(Error::SQLVariationError),
+    };
+
+    let sql = format!("DROP INDEX {}", drop_index.name);
+    // ORA-01418: specified index does not exist
+    execute_drop(conn_info, &sql, drop_index.if_exists, 1418)
+}
+
+pub(crate) fn oracle_build_drop_view(drop_view: DropView) -> Result<(), Error> {
+    let conn_info = match &drop_view.connect {
+        SQLImplementation::Oracle(oracle_connect) => oracle_connect,
+        SQLImplementation::SQLite(_) => return Err(Error::SQLVariationError),
+    };
+
+    let sql = format!("DROP VIEW {}", drop_view.name);
+    // ORA-00942: table or view does not exist
+    execute_drop(conn_info, &sql, drop_view.if_exists, 942)
+}
+
+// Oracle doesn't support IF EXISTS until 23, so the "does not exist" error is ignored instead
+fn execute_drop(
+    conn_info: &OracleConnect,
+    sql: &str,
+    if_exists: bool,
+    does_not_exist_code: i32,
+) -> Result<(), Error> {
+    let conn: oracle::Connection = oracle::Connection::connect(
+        &conn_info.username,
+        &conn_info.password,
+        &conn_info.connection_string,
+    )?;
+    match conn.execute(sql, &[]) {
+        Ok(_) => Ok(()),
+        Err(e) if if_exists && e.oci_code() == Some(does_not_exist_code) => Ok(()),
+        Err(e) => Err(Error::OracleError(e)),
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{
+    Error, SQLImplementation,
+    statements::drop::{DropIndex, DropTable, DropView},
+};
+
+pub(crate) fn sqlite_build_drop_table(drop_table: DropTable) -> Result<(), Error> {
+    let conn_info = match &drop_table.connect {
+        SQLImplementation::Oracle(_) => return Err(Error::SQLVariationError),
+        SQLImplementation::SQLite(connect) => connect,
+    };
+
+    let sql = format!(
+        "DROP TABLE {}{}",
+        fmt_if_exists(drop_table.if_exists),
+        drop_table.name
+    );
+    let conn = conn_info.initialize_connection()?;
+    conn.execute(&sql, ())?;
+    Ok(())
+}
+
+pub(crate) fn sqlite_build_drop_index(drop_index: DropIndex) -> Result<(), Error> {
+    let conn_info = match &drop_index.connect {
+        SQLImplementation::Oracle(_) => return Err(Error::SQLVariationError),
+        SQLImplementation::SQLite(connect) => connect,
+    };
+
+    let sql = format!(
+        "DROP INDEX {}{}",
+        fmt_if_exists(drop_index.if_exists),
+        drop_index.name
+    );
+    let conn = conn_info.initialize_connection()?;
+    conn.execute(&sql, ())?;
+    Ok(())
+}
+
+pub(crate) fn sqlite_build_drop_view(drop_view: DropView) -> Result<(), Error> {
+    let conn_info = match &drop_view.connect {
+        SQLImplementation::Oracle(_) => return Err(Error::SQLVariationError),
+        SQLImplementation::SQLite(connect) => connect,
+    };
+
+    let sql = format!(
+        "DROP VIEW {}{}",
+        fmt_if_exists(drop_view.if_exists),
+        drop_view.name
+    );
+    let conn = conn_info.initialize_connection()?;
+    conn.execute(&sql, ())?;
+    Ok(())
+}
+
+fn fmt_if_exists(if_exists: bool) -> &'static str {
+    if if_exists { "IF EXISTS " } else { "" }
+}
@@ -0,0 +1,280 @@
+use std::{
+    collections::HashSet,
+    fs::{File, OpenOptions},
+    hash::Hasher,
+    io::{BufRead, BufReader, Write},
+    sync::Mutex,
+};
+
+use crate::{
+    Error, SQLImplementation,
+    data_types::SQLDataTypes,
+    sql_implementations::{OracleConnect, SQLiteConnect},
+    statements::insert::sql_implementations::oracle::validation::does_table_exist,
+};
+
+use super::{Checkpoint, InsertProps};
+
+/// Where the committed batches of one load are recorded.
+/// Loads are told apart by a fingerprint of the table, header, chunk size and every row, followed by the row count,
+/// so resuming with different input starts over instead of skipping the wrong rows.
+pub(crate) struct CheckpointLog {
+    checkpoint: Checkpoint,
+    table: String,
+    load_id: String,
+    file: Option<Mutex<File>>,
+}
+
+impl CheckpointLog {
+    /// Opens the checkpoint and returns the offsets of the batches already committed when resuming.
+    /// Without resuming, the load's earlier checkpoints are cleared.
+    pub(crate) fn open(
+        insert_props: &InsertProps,
+    ) -> Result<Option<(Self, HashSet<usize>)>, Error> {
+        let checkpoint = match &insert_props.checkpoint {
+            Some(checkpoint) => checkpoint.to_owned(),
+            None => return Ok(None),
+        };
+        let mut log = CheckpointLog {
+            checkpoint,
+            table: insert_props.table.to_string(),
+            load_id: fingerprint(insert_props),
+            file: None,
+        };
+        let committed = match &insert_props.checkpoint {
+            Some(Checkpoint::File(path)) => log.open_file(path, insert_props.resume)?,
+            Some(Checkpoint::Table(table)) => match &insert_props.connect {
+                SQLImplementation::Oracle(connect) => {
+                    log.open_oracle_table(connect, table, insert_props.resume)?
+                }
+                SQLImplementation::SQLite(connect) => {
+                    log.open_sqlite_table(connect, table, insert_props.resume)?
+                }
+            },
+            None => HashSet::new(),
+        };
+        Ok(Some((log, committed)))
+    }
+
+    /// Records a batch inside its Oracle transaction, so the batch and its checkpoint are committed together.
+    pub(crate) fn record_oracle(
+        &self,
+        conn: &oracle::Connection,
+        offset: usize,
+    ) -> Result<(), Error> {
+        if let Checkpoint::Table(table) = &self.checkpoint {
+            conn.execute(
+                &format!(
+                    "INSERT INTO {table} (table_name, load_id, batch_offset) VALUES (:1, :2, :3)"
+                ),
+                &[&self.table, &self.load_id, &(offset as u64)],
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Records a batch inside its SQLite transaction, so the batch and its checkpoint are committed together.
+    pub(crate) fn record_sqlite(
+        &self,
+        conn: &rusqlite::Connection,
+        offset: usize,
+    ) -> Result<(), Error> {
+        if let Checkpoint::Table(table) = &self.checkpoint {
+            conn.execute(
+                &format!(
+                    "INSERT INTO {table} (table_name, load_id, batch_offset) VALUES (?1, ?2, ?3)"
+                ),
+                rusqlite::params![self.table, self.load_id, offset as i64],
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Records a batch in the checkpoint file once it's committed.
+    pub(crate) fn committed(&self, offset: usize) -> Result<(), Error> {
+        if let Some(file) = &self.file {
+            let mut file = file.lock().unwrap();
+            writeln!(file, "{} {}", self.load_id, offset)?;
+            file.flush()?;
+        }
+        Ok(())
+    }
+
+    fn open_file(&mut self, path: &str, resume: bool) -> Result<HashSet<usize>, Error> {
+        let mut committed = HashSet::new();
+        let mut other_loads = Vec::new();
+        if let Ok(file) = File::open(path) {
+            for line in BufReader::new(file).lines() {
+                let line = line?;
+                match line.split_once(' ') {
+                    Some((load_id, offset)) if load_id == self.load_id => {
+                        if resume && let Ok(offset) = offset.trim().parse::<usize>() {
+                            committed.insert(offset);
+                        }
+                    }
+                    _ => other_loads.push(line),
+                }
+            }
+        }
+        if !resume {
+            // Only this load's lines are cleared, the other loads sharing the file keep theirs
+            let mut file = File::create(path)?;
+            for line in &other_loads {
+                writeln!(file, "{line}")?;
+            }
+        }
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        self.file = Some(Mutex::new(file));
+        Ok(committed)
+    }
+
+    fn open_oracle_table(
+        &self,
+        connect: &OracleConnect,
+        table: &str,
+        resume: bool,
+    ) -> Result<HashSet<usize>, Error> {
+        let conn = oracle::Connection::connect(
+            &connect.username,
+            &connect.password,
+            &connect.connection_string,
+        )?;
+        if !does_table_exist(&table.to_string(), connect)? {
+            conn.execute(
+                &format!(
+                    "CREATE TABLE {table} (table_name VARCHAR2(128), load_id VARCHAR2(64), batch_offset NUMBER)"
+                ),
+                &[],
+            )?;
+        }
+        let mut committed = HashSet::new();
+        if resume {
+            let rows = conn.query_as::<u64>(
+                &format!("SELECT batch_offset FROM {table} WHERE load_id = :1"),
+                &[&self.load_id],
+            )?;
+            for offset in rows {
+                committed.insert(offset? as usize);
+            }
+        } else {
+            conn.execute(
+                &format!("DELETE FROM {table} WHERE load_id = :1"),
+                &[&self.load_id],
+            )?;
+            conn.commit()?;
+        }
+        Ok(committed)
+    }
+
+    fn open_sqlite_table(
+        &self,
+        connect: &SQLiteConnect,
+        table: &str,
+        resume: bool,
+    ) -> Result<HashSet<usize>, Error> {
+        let conn = connect.initialize_connection()?;
+        conn.execute(
+            &format!(
+                "CREATE TABLE IF NOT EXISTS {table} (table_name TEXT, load_id TEXT, batch_offset INTEGER)"
+            ),
+            [],
+        )?;
+        let mut committed = HashSet::new();
+        if resume {
+            let mut stmt = conn.prepare(&format!(
+                "SELECT batch_offset FROM {table} WHERE load_id = ?1"
+            ))?;
+            let rows = stmt.query_map([&self.load_id], |row| row.get::<usize, i64>(0))?;
+            for offset in rows {
+                committed.insert(offset? as usize);
+            }
+        } else {
+            conn.execute(
+                &format!("DELETE FROM {table} WHERE load_id = ?1"),
+                [&self.load_id],
+            )?;
+        }
+        Ok(committed)
+    }
+}
+
+fn fingerprint(insert_props: &InsertProps) -> String {
+    let mut hasher = Fnv1a::new();
+    hasher.write_str(&insert_props.table.to_ascii_lowercase());
+    for head in &insert_props.header {
+        hasher.write_str(head);
+    }
+    hasher.write_u64(insert_props.chunk_size as u64);
+    for row in &insert_props.grid {
+        for cell in row {
+            hasher.write_u8(type_tag(cell));
+            hasher.write_str(&cell.to_string());
+        }
+    }
+    format!("{:016x}-{}", hasher.finish(), insert_props.grid.len())
+}
+
+/// 64-bit FNV-1a. `DefaultHasher` can change between Rust releases,
+/// which would stop a rebuilt program from finding its checkpoints.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Fnv1a(0xcbf2_9ce4_8422_2325)
+    }
+
+    // The length goes first, so ("ab", "c") and ("a", "bc") hash differently
+    fn write_str(&mut self, val: &str) {
+        self.write_u64(val.len() as u64);
+        self.write(val.as_bytes());
+    }
+}
+
+impl Hasher for Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    fn write_u64(&mut self, val: u64) {
+        self.write(&val.to_le_bytes());
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+fn type_tag(cell: &SQLDataTypes) -> u8 {
+    match cell {
+        SQLDataTypes::Varchar(_) => 0,
+        SQLDataTypes::Number(_) => 1,
+        SQLDataTypes::Float(_) => 2,
+        SQLDataTypes::Decimal(_) => 3,
+        SQLDataTypes::Date(_) => 4,
+        SQLDataTypes::Time(_) => 5,
+        SQLDataTypes::Timestamp(_) => 6,
+        SQLDataTypes::TimestampTz(_) => 7,
+        SQLDataTypes::Interval(_) => 8,
+        SQLDataTypes::Bool(_) => 9,
+        SQLDataTypes::Json(_) => 10,
+        SQLDataTypes::NULL => 11,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::Hasher;
+
+    use super::Fnv1a;
+
+    #[test]
+    fn fnv1a_matches_reference_values() {
+        let mut hasher = Fnv1a::new();
+        assert_eq!(hasher.finish(), 0xcbf2_9ce4_8422_2325);
+        hasher.write(b"a");
+        assert_eq!(hasher.finish(), 0xaf63_dc4c_8601_ec8c);
+    }
+}
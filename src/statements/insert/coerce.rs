@@ -0,0 +1,237 @@
+use std::str::FromStr;
+
+use bigdecimal::BigDecimal;
+use chrono::DateTime;
+
+use crate::{
+    Error,
+    data_types::{SQLDataTypes, SQLInterval},
+    statements::{
+        create::CreateDataTypes,
+        insert::data_conversion::{
+            date::{date_conversion, date_w_abbrv_conversion},
+            datetime::datetime_conversion,
+            time::time_conversion,
+        },
+    },
+};
+
+use super::{InsertProps, ParseOptions, ValidationIssue};
+
+impl InsertProps {
+    /// Converts every cell to the type of the existing table's column when
+    /// [`coerce_to_table_types`](super::InsertBuilder::coerce_to_table_types) is set.
+    /// Runs after the columns are mapped, so the header holds the table's column names.
+    pub(crate) fn coerce_grid(mut self) -> Result<Self, Error> {
+        if !self.coerce {
+            return Ok(self);
+        }
+        let schema = match self.connect.describe_table(&self.table) {
+            Ok(schema) => schema,
+            // A table created by the insert takes its types from the grid
+            Err(Error::TableDoesNotExist) => return Ok(self),
+            Err(err) => return Err(err),
+        };
+        // Columns the table doesn't have are left as they are
+        let types = self
+            .header
+            .iter()
+            .map(|head| {
+                schema
+                    .columns
+                    .iter()
+                    .find(|column| column.name.eq_ignore_ascii_case(head))
+                    .map(|column| (column.name.to_string(), column.data_type.clone()))
+            })
+            .collect::<Vec<Option<(String, CreateDataTypes)>>>();
+
+        let mut issues = Vec::new();
+        for (row_idx, row) in self.grid.iter_mut().enumerate() {
+            for (cell, column) in row.iter_mut().zip(&types) {
+                let Some((name, data_type)) = column else {
+                    continue;
+                };
+                match coerce_cell(cell, data_type, &self.parse_options) {
+                    Some(coerced) => *cell = coerced,
+                    None => issues.push(coerce_issue(row_idx, name, data_type, cell)),
+                }
+            }
+        }
+        match issues.is_empty() {
+            true => Ok(self),
+            false => Err(Error::CoercionError(issues)),
+        }
+    }
+}
+
+// Returns None when the cell can't be stored in the column's type.
+// Text going into a non-text column is first parsed with the insert's ParseOptions,
+// so day first dates, separators and null tokens are read the same way `format_grid_strings_with` reads them
+fn coerce_cell(
+    cell: &SQLDataTypes,
+    data_type: &CreateDataTypes,
+    options: &ParseOptions,
+) -> Option<SQLDataTypes> {
+    if let SQLDataTypes::Varchar(val) = cell
+        && !matches!(
+            data_type,
+            CreateDataTypes::VARCHAR(_) | CreateDataTypes::CLOB
+        )
+    {
+        if val.trim().is_empty() {
+            return Some(SQLDataTypes::NULL);
+        }
+        let mut parsed = cell.to_owned();
+        parsed.format_data_types_with(options);
+        let parsed = match (data_type, parsed) {
+            (_, SQLDataTypes::Varchar(_)) => None,
+            // Keeps the digits as written instead of going through an f64
+            (CreateDataTypes::NUMBER(..), SQLDataTypes::Float(val)) => {
+                BigDecimal::from_str(&val.to_string())
+                    .ok()
+                    .map(SQLDataTypes::Decimal)
+            }
+            (_, parsed) => Some(parsed),
+        };
+        if let Some(coerced) = parsed.and_then(|parsed| coerce_value(&parsed, data_type)) {
+            return Some(coerced);
+        }
+    }
+    coerce_value(cell, data_type)
+}
+
+fn coerce_value(cell: &SQLDataTypes, data_type: &CreateDataTypes) -> Option<SQLDataTypes> {
+    let coerced = match (data_type, cell) {
+        (_, SQLDataTypes::NULL) => SQLDataTypes::NULL,
+        (CreateDataTypes::VARCHAR(_) | CreateDataTypes::CLOB, SQLDataTypes::Varchar(_)) => {
+            cell.to_owned()
+        }
+        (CreateDataTypes::VARCHAR(_) | CreateDataTypes::CLOB, _) => {
+            SQLDataTypes::Varchar(cell.to_string())
+        }
+        (CreateDataTypes::NUMBER(..), SQLDataTypes::Varchar(val)) => {
+            let val = val.trim();
+            match val.parse::<i64>() {
+                Ok(int) => SQLDataTypes::Number(int),
+                Err(_) => SQLDataTypes::Decimal(BigDecimal::from_str(val).ok()?),
+            }
+        }
+        (CreateDataTypes::FLOAT, SQLDataTypes::Varchar(val)) => {
+            SQLDataTypes::Float(val.trim().parse::<f64>().ok()?)
+        }
+        (
+            CreateDataTypes::NUMBER(..) | CreateDataTypes::FLOAT,
+            SQLDataTypes::Number(_) | SQLDataTypes::Float(_) | SQLDataTypes::Decimal(_),
+        ) => cell.to_owned(),
+        (CreateDataTypes::NUMBER(..) | CreateDataTypes::FLOAT, SQLDataTypes::Bool(val)) => {
+            SQLDataTypes::Number(*val as i64)
+        }
+        (CreateDataTypes::DATE, SQLDataTypes::Varchar(val)) => {
+            let mut val = val.trim().to_string();
+            match date_conversion(&mut val).or_else(|_| date_w_abbrv_conversion(&mut val)) {
+                Ok(date) => SQLDataTypes::Date(date),
+                // Oracle's DATE keeps the time of day
+                Err(_) => SQLDataTypes::Timestamp(datetime_conversion(&mut val).ok()?),
+            }
+        }
+        (CreateDataTypes::TIMESTAMP, SQLDataTypes::Varchar(val)) => {
+            let mut val = val.trim().to_string();
+            match datetime_conversion(&mut val) {
+                Ok(datetime) => SQLDataTypes::Timestamp(datetime),
+                Err(_) => SQLDataTypes::Timestamp(
+                    date_conversion(&mut val)
+                        .or_else(|_| date_w_abbrv_conversion(&mut val))
+                        .ok()?
+                        .and_hms_opt(0, 0, 0)?,
+                ),
+            }
+        }
+        (CreateDataTypes::TIMESTAMP, SQLDataTypes::Date(date)) => {
+            SQLDataTypes::Timestamp(date.and_hms_opt(0, 0, 0)?)
+        }
+        (CreateDataTypes::TIMESTAMPTZ, SQLDataTypes::Varchar(val)) => {
+            let val = val.trim();
+            match DateTime::parse_from_rfc3339(val) {
+                Ok(datetime) => SQLDataTypes::TimestampTz(datetime),
+                Err(_) => SQLDataTypes::TimestampTz(
+                    DateTime::parse_from_str(val, "%Y-%m-%d %H:%M:%S%.f %:z").ok()?,
+                ),
+            }
+        }
+        (
+            CreateDataTypes::DATE | CreateDataTypes::TIMESTAMP | CreateDataTypes::TIMESTAMPTZ,
+            SQLDataTypes::Date(_) | SQLDataTypes::Timestamp(_) | SQLDataTypes::TimestampTz(_),
+        ) => cell.to_owned(),
+        (CreateDataTypes::TIME, SQLDataTypes::Varchar(val)) => {
+            SQLDataTypes::Time(time_conversion(val.trim()).ok()?)
+        }
+        (CreateDataTypes::TIME, SQLDataTypes::Time(_)) => cell.to_owned(),
+        (CreateDataTypes::TIME, SQLDataTypes::Timestamp(datetime)) => {
+            SQLDataTypes::Time(datetime.time())
+        }
+        (CreateDataTypes::INTERVAL, SQLDataTypes::Interval(SQLInterval::DayToSecond(_)))
+        | (CreateDataTypes::INTERVALYM, SQLDataTypes::Interval(SQLInterval::YearToMonth { .. })) => {
+            cell.to_owned()
+        }
+        (CreateDataTypes::INTERVAL | CreateDataTypes::INTERVALYM, SQLDataTypes::Varchar(val)) => {
+            match (data_type, SQLInterval::parse(val)?) {
+                (CreateDataTypes::INTERVAL, interval @ SQLInterval::DayToSecond(_))
+                | (CreateDataTypes::INTERVALYM, interval @ SQLInterval::YearToMonth { .. }) => {
+                    SQLDataTypes::Interval(interval)
+                }
+                _ => return None,
+            }
+        }
+        (CreateDataTypes::BOOL, SQLDataTypes::Bool(_)) => cell.to_owned(),
+        (CreateDataTypes::BOOL, SQLDataTypes::Number(val)) if *val == 0 || *val == 1 => {
+            SQLDataTypes::Bool(*val == 1)
+        }
+        (CreateDataTypes::BOOL, SQLDataTypes::Varchar(val)) => {
+            match val.trim().to_ascii_lowercase().as_str() {
+                "true" | "yes" | "1" => SQLDataTypes::Bool(true),
+                "false" | "no" | "0" => SQLDataTypes::Bool(false),
+                _ => return None,
+            }
+        }
+        (CreateDataTypes::JSON, SQLDataTypes::Varchar(val)) => {
+            SQLDataTypes::Json(serde_json::from_str(val).ok()?)
+        }
+        (
+            CreateDataTypes::JSON,
+            SQLDataTypes::Json(_)
+            | SQLDataTypes::Number(_)
+            | SQLDataTypes::Float(_)
+            | SQLDataTypes::Decimal(_)
+            | SQLDataTypes::Bool(_),
+        ) => cell.to_owned(),
+        _ => return None,
+    };
+    Some(coerced)
+}
+
+fn coerce_issue(
+    row: usize,
+    column: &str,
+    data_type: &CreateDataTypes,
+    cell: &SQLDataTypes,
+) -> ValidationIssue {
+    match (data_type, cell) {
+        (
+            CreateDataTypes::DATE
+            | CreateDataTypes::TIME
+            | CreateDataTypes::TIMESTAMP
+            | CreateDataTypes::TIMESTAMPTZ,
+            SQLDataTypes::Varchar(val),
+        ) => ValidationIssue::UnparseableDate {
+            row,
+            column: column.to_string(),
+            value: val.to_string(),
+        },
+        _ => ValidationIssue::TypeConflict {
+            row,
+            column: column.to_string(),
+            expected: data_type.clone(),
+            value: cell.to_owned(),
+        },
+    }
+}
@@ -1,36 +1,85 @@
+use std::str::FromStr;
+
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime};
 use date::{date_conversion, date_w_abbrv_conversion};
 use datetime::datetime_conversion;
+use itertools::Itertools;
+use time::time_conversion;
 
-use crate::data_types::SQLDataTypes;
+use crate::{
+    data_types::SQLDataTypes,
+    statements::insert::{DateOrder, ParseOptions},
+};
 
 pub mod date;
 pub mod datetime;
 pub mod dt_regex;
+pub mod time;
 
 impl SQLDataTypes {
     pub fn format_data_types(&mut self) -> &mut SQLDataTypes {
+        self.format_data_types_with(&ParseOptions::default())
+    }
+
+    /// Same as [`format_data_types`](SQLDataTypes::format_data_types), with the date order, formats,
+    /// number separators and null tokens of the [`ParseOptions`].
+    pub fn format_data_types_with(&mut self, options: &ParseOptions) -> &mut SQLDataTypes {
         let val = if let SQLDataTypes::Varchar(val) = self {
             val
         } else {
             return self;
         };
-        if val.trim().len() == 0 {
+        if val.trim().is_empty()
+            || options
+                .null_tokens
+                .iter()
+                .any(|token| token.eq_ignore_ascii_case(val.trim()))
+        {
             *self = SQLDataTypes::NULL;
             return self;
         }
+        if let Some(bool) = bool_match(val) {
+            *self = SQLDataTypes::Bool(bool);
+            return self;
+        }
         if !contains_number(val) {
             return self;
         }
-        if let Ok(int) = val.parse::<i64>() {
-            *self = SQLDataTypes::Number(int);
+        if options.keep_leading_zeros && has_leading_zero(val) {
+            return self;
+        }
+        if let Some(val) = format_match(val, &options.formats) {
+            *self = val;
             return self;
         }
-        if let Ok(float) = val.parse::<f64>() {
-            *self = SQLDataTypes::Float(float);
+        let number = normalize_number(val, options);
+        // A '.' that isn't grouping thousands can't be told apart from a decimal point, so it's left as text
+        let ambiguous = number.is_none() && options.decimal_separator != '.' && val.contains('.');
+        let num = number.as_deref().unwrap_or(val);
+        if !ambiguous {
+            if let Ok(int) = num.parse::<i64>() {
+                *self = SQLDataTypes::Number(int);
+                return self;
+            }
+            if let Some(decimal) = decimal_match(num) {
+                // Oracle's NUMBER keeps at most 38 digits, so longer numbers stay text instead of being rounded
+                if decimal.digits() <= 38 {
+                    *self = SQLDataTypes::Decimal(decimal);
+                }
+                return self;
+            }
+            if let Ok(float) = num.parse::<f64>() {
+                *self = SQLDataTypes::Float(float);
+                return self;
+            }
+        }
+        if let Some(val) = iso_match(val) {
+            *self = val;
             return self;
         }
         if contains_number(val) && is_dt(val) {
-            if let Some(val) = date_match(val) {
+            if let Some(val) = date_match(val, options.date_order) {
                 *self = val;
                 return self;
             }
@@ -39,6 +88,66 @@ impl SQLDataTypes {
     }
 }
 
+/// Formats every cell of a grid with [`format_data_types_with`](SQLDataTypes::format_data_types_with).
+/// `true`/`false`/`yes`/`no` only become a `Bool` in columns where everything else is a `Bool` or NULL,
+/// so a "No" next to a "Maybe" (or the "NO" country code) is inserted as it was written.
+/// Likewise, a column with any `Decimal` has its `Float`s turned into `Decimal`s, so "1.50" and "1.5" end up the same type.
+pub(crate) fn format_grid(grid: &mut [Vec<SQLDataTypes>], options: &ParseOptions) {
+    let mut bools = Vec::new();
+    for (y_idx, row) in grid.iter_mut().enumerate() {
+        for (x_idx, cell) in row.iter_mut().enumerate() {
+            let text = match cell {
+                SQLDataTypes::Varchar(val) if bool_match(val).is_some() => Some(val.to_owned()),
+                _ => None,
+            };
+            cell.format_data_types_with(options);
+            if let Some(text) = text
+                && let SQLDataTypes::Bool(_) = cell
+            {
+                bools.push((y_idx, x_idx, text));
+            }
+        }
+    }
+
+    let mixed = bools
+        .iter()
+        .map(|(_, x_idx, _)| *x_idx)
+        .unique()
+        .filter(|x_idx| {
+            grid.iter().any(|row| {
+                !matches!(
+                    row.get(*x_idx),
+                    None | Some(SQLDataTypes::Bool(_)) | Some(SQLDataTypes::NULL)
+                )
+            })
+        })
+        .collect::<Vec<usize>>();
+    for (y_idx, x_idx, text) in bools {
+        if mixed.contains(&x_idx) {
+            grid[y_idx][x_idx] = SQLDataTypes::Varchar(text);
+        }
+    }
+
+    let decimals = grid
+        .iter()
+        .flat_map(|row| {
+            row.iter()
+                .positions(|cell| matches!(cell, SQLDataTypes::Decimal(_)))
+        })
+        .unique()
+        .collect::<Vec<usize>>();
+    for row in grid.iter_mut() {
+        for x_idx in decimals.iter() {
+            if let Some(cell) = row.get_mut(*x_idx)
+                && let SQLDataTypes::Float(val) = cell
+                && let Ok(decimal) = BigDecimal::from_str(&val.to_string())
+            {
+                *cell = SQLDataTypes::Decimal(decimal);
+            }
+        }
+    }
+}
+
 fn contains_number(input: &mut String) -> bool {
     let is_num = input
         .trim()
@@ -52,25 +161,360 @@ fn contains_number(input: &mut String) -> bool {
     }
 }
 
+// Plain decimals that an f64 can't represent as written, like "1234.5600" or IDs wider than an i64
+fn decimal_match(input: &str) -> Option<BigDecimal> {
+    let val = input.trim();
+    if !val
+        .chars()
+        .all(|char| char.is_ascii_digit() || char == '.' || char == '-')
+    {
+        return None;
+    }
+    let float = val.parse::<f64>().ok()?;
+    if float.to_string() == val {
+        return None;
+    }
+    BigDecimal::from_str(val).ok()
+}
+
+fn bool_match(input: &str) -> Option<bool> {
+    match input.trim().to_ascii_lowercase().as_str() {
+        "true" | "yes" => Some(true),
+        "false" | "no" => Some(false),
+        _ => None,
+    }
+}
+
 fn is_dt(input: &mut String) -> bool {
-    if input.contains("/") || input.contains("-") || input.contains(":") {
-        return true;
-    } else {
-        return false;
+    input.contains("/") || input.contains("-") || input.contains(":") || is_dotted_date(input)
+}
+
+// A '.' is also a decimal point, so only a full `dd.mm.yyyy` date (and any time after it) is let through
+fn is_dotted_date(input: &str) -> bool {
+    let date = input.trim().split(' ').next().unwrap_or_default();
+    let parts = date.split('.').collect::<Vec<&str>>();
+    match parts[..] {
+        [day, month, year] => {
+            [day, month, year]
+                .iter()
+                .all(|part| !part.is_empty() && part.chars().all(|char| char.is_ascii_digit()))
+                && day.len() <= 2
+                && month.len() <= 2
+                && year.len() == 4
+        }
+        _ => false,
     }
 }
 
-fn date_match(val: &mut String) -> Option<SQLDataTypes> {
-    if let Ok(dt) = datetime_conversion(val) {
-        return Some(SQLDataTypes::Date(dt));
+fn date_match(val: &mut String, date_order: DateOrder) -> Option<SQLDataTypes> {
+    let mut ordered = match date_order {
+        DateOrder::MonthFirst => val.to_string(),
+        DateOrder::DayFirst => swap_day_month(val),
+    };
+    if let Ok(dt) = datetime_conversion(&mut ordered) {
+        return Some(SQLDataTypes::Timestamp(dt));
     }
-    if let Ok(date) = date_conversion(val) {
-        let dt = date.and_hms_milli_opt(0, 0, 0, 0).unwrap();
-        return Some(SQLDataTypes::Date(dt));
+    if let Ok(date) = date_conversion(&mut ordered) {
+        return Some(SQLDataTypes::Date(date));
     }
     if let Ok(date) = date_w_abbrv_conversion(val) {
-        let dt = date.and_hms_milli_opt(0, 0, 0, 0).unwrap();
-        return Some(SQLDataTypes::Date(dt));
+        return Some(SQLDataTypes::Date(date));
+    }
+    if let Ok(time) = time_conversion(val) {
+        return Some(SQLDataTypes::Time(time));
     }
     None
 }
+
+/// Turns seconds since 1970-01-01 UTC into a `Timestamp`, leaving anything that isn't a number as it is.
+pub(crate) fn epoch_conversion(cell: &mut SQLDataTypes) {
+    let seconds = match cell {
+        SQLDataTypes::Number(val) => *val as f64,
+        SQLDataTypes::Float(val) => *val,
+        SQLDataTypes::Decimal(val) => match val.to_string().parse::<f64>() {
+            Ok(val) => val,
+            Err(_) => return,
+        },
+        _ => return,
+    };
+    let nanos = (seconds.fract() * 1_000_000_000.0).round() as u32;
+    if let Some(dt) = DateTime::from_timestamp(seconds.trunc() as i64, nanos) {
+        *cell = SQLDataTypes::Timestamp(dt.naive_utc());
+    }
+}
+
+// The month first parsers read `dd/mm/yyyy` (and any time after it) once the day and month are swapped
+fn swap_day_month(input: &str) -> String {
+    let val = input.trim();
+    let (date, time) = match val.split_once(' ') {
+        Some((date, time)) => (date, Some(time)),
+        None => (val, None),
+    };
+    let parts = date.split(['/', '-', '.']).collect::<Vec<&str>>();
+    match parts[..] {
+        [day, month, year] if day.len() <= 2 && (year.len() == 2 || year.len() == 4) => {
+            match time {
+                Some(time) => format!("{month}-{day}-{year} {time}"),
+                None => format!("{month}-{day}-{year}"),
+            }
+        }
+        _ => input.to_string(),
+    }
+}
+
+// ISO 8601 timestamps, with an offset (or Z) for a TimestampTz
+fn iso_match(input: &str) -> Option<SQLDataTypes> {
+    let val = input.trim();
+    if !val.contains('T') {
+        return None;
+    }
+    if let Ok(dt) = DateTime::parse_from_rfc3339(val) {
+        return Some(SQLDataTypes::TimestampTz(dt));
+    }
+    NaiveDateTime::parse_from_str(val, "%Y-%m-%dT%H:%M:%S%.f")
+        .or_else(|_| NaiveDateTime::parse_from_str(val, "%Y-%m-%dT%H:%M"))
+        .ok()
+        .map(SQLDataTypes::Timestamp)
+}
+
+fn format_match(input: &str, formats: &[String]) -> Option<SQLDataTypes> {
+    let val = input.trim();
+    formats.iter().find_map(|format| {
+        if let Ok(dt) = DateTime::parse_from_str(val, format) {
+            return Some(SQLDataTypes::TimestampTz(dt));
+        }
+        if let Ok(dt) = NaiveDateTime::parse_from_str(val, format) {
+            return Some(SQLDataTypes::Timestamp(dt));
+        }
+        if let Ok(date) = NaiveDate::parse_from_str(val, format) {
+            return Some(SQLDataTypes::Date(date));
+        }
+        NaiveTime::parse_from_str(val, format)
+            .ok()
+            .map(SQLDataTypes::Time)
+    })
+}
+
+// Codes like ZIPs and IDs, where the zeros would be lost as a number
+fn has_leading_zero(input: &str) -> bool {
+    let val = input.trim();
+    val.len() > 1 && val.starts_with('0') && val.chars().all(|char| char.is_ascii_digit())
+}
+
+/// Strips the currency symbols, percent sign, thousands separators and accounting parentheses the
+/// [`ParseOptions`] allow, and returns the plain number. Returns None when the text isn't a number or nothing changed.
+fn normalize_number(input: &str, options: &ParseOptions) -> Option<String> {
+    let mut val = input.trim();
+    let mut negative = false;
+    if options.accounting_negatives
+        && let Some(inner) = val.strip_prefix('(').and_then(|val| val.strip_suffix(')'))
+    {
+        negative = true;
+        val = inner.trim();
+    }
+    if let Some(inner) = val.strip_prefix('-') {
+        negative = !negative;
+        val = inner.trim();
+    }
+    for symbol in &options.currency_symbols {
+        if let Some(inner) = val.strip_prefix(symbol.as_str()) {
+            val = inner.trim();
+        } else if let Some(inner) = val.strip_suffix(symbol.as_str()) {
+            val = inner.trim();
+        }
+    }
+    let percent = match val.strip_suffix('%') {
+        Some(inner) if options.percentages => {
+            val = inner.trim();
+            true
+        }
+        _ => false,
+    };
+
+    let (int_part, fraction) = match val.split_once(options.decimal_separator) {
+        Some((int_part, fraction)) => (int_part, Some(fraction)),
+        None => (val, None),
+    };
+    let int_part = match options.thousands_separator {
+        Some(separator) if int_part.contains(separator) => {
+            let groups = int_part.split(separator).collect::<Vec<&str>>();
+            if groups[0].is_empty()
+                || groups[0].len() > 3
+                || groups[1..].iter().any(|group| group.len() != 3)
+            {
+                return None;
+            }
+            groups.concat()
+        }
+        _ => int_part.to_string(),
+    };
+    if int_part.is_empty()
+        || !int_part.chars().all(|char| char.is_ascii_digit())
+        || !fraction
+            .unwrap_or("0")
+            .chars()
+            .all(|char| char.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let mut number = int_part;
+    if let Some(fraction) = fraction {
+        number = format!("{number}.{fraction}");
+    }
+    if negative {
+        number = format!("-{number}");
+    }
+    if percent {
+        let decimal = BigDecimal::from_str(&number).ok()? / BigDecimal::from(100);
+        number = decimal.normalized().to_plain_string();
+    }
+    match number == input.trim() {
+        true => None,
+        false => Some(number),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use bigdecimal::BigDecimal;
+    use chrono::NaiveDate;
+
+    use crate::{
+        data_types::SQLDataTypes,
+        statements::insert::{DateOrder, ParseOptions},
+    };
+
+    use super::format_grid;
+
+    fn text(val: &str) -> SQLDataTypes {
+        SQLDataTypes::Varchar(val.to_string())
+    }
+
+    #[test]
+    fn bools_in_mixed_columns_stay_text() {
+        let mut grid = vec![
+            vec![text("Yes"), text("yes"), text("NO")],
+            vec![text("No"), text("FALSE"), text("US")],
+            vec![text("Maybe"), text(""), text("DE")],
+        ];
+        format_grid(&mut grid, &ParseOptions::default());
+        assert_eq!(
+            grid,
+            vec![
+                vec![text("Yes"), SQLDataTypes::Bool(true), text("NO")],
+                vec![text("No"), SQLDataTypes::Bool(false), text("US")],
+                vec![text("Maybe"), SQLDataTypes::NULL, text("DE")],
+            ]
+        );
+    }
+
+    fn formatted(val: &str, options: &ParseOptions) -> SQLDataTypes {
+        text(val).format_data_types_with(options).to_owned()
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> SQLDataTypes {
+        SQLDataTypes::Date(NaiveDate::from_ymd_opt(year, month, day).unwrap())
+    }
+
+    #[test]
+    fn separators_are_removed_from_numbers() {
+        let us = ParseOptions {
+            thousands_separator: Some(','),
+            ..Default::default()
+        };
+        assert_eq!(formatted("1,234,567", &us), SQLDataTypes::Number(1_234_567));
+        assert_eq!(formatted("1,234.5", &us), SQLDataTypes::Float(1234.5));
+        // Not grouped in threes, so it isn't a number
+        assert_eq!(formatted("12,34", &us), text("12,34"));
+
+        let european = ParseOptions {
+            decimal_separator: ',',
+            thousands_separator: Some('.'),
+            ..Default::default()
+        };
+        assert_eq!(formatted("1.234,5", &european), SQLDataTypes::Float(1234.5));
+        assert_eq!(formatted("3,25", &european), SQLDataTypes::Float(3.25));
+    }
+
+    #[test]
+    fn accounting_negatives_and_percentages() {
+        let options = ParseOptions {
+            thousands_separator: Some(','),
+            currency_symbols: vec!["$".to_string()],
+            accounting_negatives: true,
+            percentages: true,
+            ..Default::default()
+        };
+        assert_eq!(formatted("(42)", &options), SQLDataTypes::Number(-42));
+        assert_eq!(
+            formatted("($1,234.50)", &options),
+            SQLDataTypes::Decimal(BigDecimal::from_str("-1234.50").unwrap())
+        );
+        assert_eq!(formatted("12.5%", &options), SQLDataTypes::Float(0.125));
+        assert_eq!(formatted("50%", &options), SQLDataTypes::Float(0.5));
+
+        // Off by default
+        assert_eq!(formatted("(42)", &ParseOptions::default()), text("(42)"));
+        assert_eq!(formatted("50%", &ParseOptions::default()), text("50%"));
+    }
+
+    #[test]
+    fn day_first_swaps_day_and_month() {
+        let day_first = ParseOptions {
+            date_order: DateOrder::DayFirst,
+            ..Default::default()
+        };
+        assert_eq!(formatted("05/03/2024", &day_first), date(2024, 3, 5));
+        assert_eq!(formatted("05-03-2024", &day_first), date(2024, 3, 5));
+        assert_eq!(
+            formatted("05/03/2024", &ParseOptions::default()),
+            date(2024, 5, 3)
+        );
+        // A day over 12 can't be a month, so either order reads it the same way
+        assert_eq!(
+            formatted("25/03/2024", &ParseOptions::default()),
+            date(2024, 3, 25)
+        );
+    }
+
+    #[test]
+    fn dotted_dates_are_parsed_but_decimals_are_not() {
+        let day_first = ParseOptions {
+            date_order: DateOrder::DayFirst,
+            ..Default::default()
+        };
+        assert_eq!(formatted("05.03.2024", &day_first), date(2024, 3, 5));
+        assert_eq!(
+            formatted("05.03.2024", &ParseOptions::default()),
+            date(2024, 5, 3)
+        );
+        assert_eq!(formatted("2.75", &day_first), SQLDataTypes::Float(2.75));
+        assert_eq!(formatted("1.2.3", &day_first), text("1.2.3"));
+        assert_eq!(formatted("10.20.30", &day_first), text("10.20.30"));
+    }
+
+    #[test]
+    fn decimals_are_inferred_per_column() {
+        let decimal = |val: &str| SQLDataTypes::Decimal(BigDecimal::from_str(val).unwrap());
+        let wide = "1".repeat(39);
+        let mut grid = vec![
+            vec![text("1.50"), text("1.5"), text(&wide)],
+            vec![text("1.5"), text("2.25"), text("1")],
+        ];
+        format_grid(&mut grid, &ParseOptions::default());
+        assert_eq!(
+            grid,
+            vec![
+                vec![decimal("1.50"), SQLDataTypes::Float(1.5), text(&wide)],
+                vec![
+                    decimal("1.5"),
+                    SQLDataTypes::Float(2.25),
+                    SQLDataTypes::Number(1)
+                ],
+            ]
+        );
+    }
+}
@@ -0,0 +1,24 @@
+use chrono::NaiveTime;
+
+pub fn time_conversion(input: &str) -> Result<NaiveTime, String> {
+    let fmt_time = input.trim().to_ascii_uppercase();
+
+    // hh:mm:ss AM
+    if let Ok(time) = NaiveTime::parse_from_str(&fmt_time, "%I:%M:%S %p") {
+        return Ok(time);
+    }
+    // hh:mm AM
+    if let Ok(time) = NaiveTime::parse_from_str(&fmt_time, "%I:%M %p") {
+        return Ok(time);
+    }
+    // hh:mm:ss.fff
+    if let Ok(time) = NaiveTime::parse_from_str(&fmt_time, "%H:%M:%S%.f") {
+        return Ok(time);
+    }
+    // hh:mm
+    if let Ok(time) = NaiveTime::parse_from_str(&fmt_time, "%H:%M") {
+        return Ok(time);
+    }
+
+    Err(input.to_string())
+}
@@ -0,0 +1,98 @@
+use crate::{
+    Error, SQLImplementation,
+    data_types::SQLDataTypes,
+    statements::{
+        alter::{AlterBuilder, AlterColumns, AlterTableBuilder},
+        create::{CreateDataTypes, sql_implementations::utils::ORACLE_VARCHAR_LIMIT},
+        insert::sql_implementations::oracle::validation::get_col_indexes,
+    },
+};
+
+use super::InsertProps;
+
+impl InsertProps {
+    /// Adds the grid columns the existing table doesn't have and widens the `VARCHAR2` columns
+    /// the grid's text is too long for, when [`evolve_schema`](super::InsertBuilder::evolve_schema) is set.
+    /// Runs after the columns are mapped, so the header holds the table's column names.
+    /// Text too long for a `VARCHAR2` on Oracle returns [`Error::VarcharLimit`] before the table is changed,
+    /// while added columns that long are created as `CLOB`s.
+    pub(crate) fn evolve_table(self) -> Result<Self, Error> {
+        if !self.evolve {
+            return Ok(self);
+        }
+        let schema = match self.connect.describe_table(&self.table) {
+            Ok(schema) => schema,
+            Err(Error::TableDoesNotExist) => return Ok(self),
+            Err(err) => return Err(err),
+        };
+
+        let is_oracle = matches!(self.connect, SQLImplementation::Oracle(_));
+        let mut added = Vec::new();
+        let mut widened = Vec::new();
+        let inferred = get_col_indexes(&self.grid)?.to_create_columns(&self.header);
+        for (idx, column) in inferred.into_iter().enumerate() {
+            let existing = schema
+                .columns
+                .iter()
+                .find(|existing| existing.name.eq_ignore_ascii_case(&column.name));
+            match existing {
+                None => added.push(AlterColumns {
+                    name: column.name,
+                    data_type: column.data_type,
+                    default: None,
+                    not_null: false,
+                }),
+                // Unsized text columns (and every SQLite column) don't have a length to outgrow
+                Some(existing) if existing.declared_type.contains('(') => {
+                    if let CreateDataTypes::VARCHAR(size) = existing.data_type {
+                        let longest = self
+                            .grid
+                            .iter()
+                            .filter_map(|row| row.get(idx))
+                            .map(|cell| match cell {
+                                SQLDataTypes::Varchar(val) => val.len(),
+                                SQLDataTypes::NULL => 0,
+                                // Coercing turns other cells into their text, otherwise they're bound as their own type
+                                cell if self.coerce => cell.to_string().len(),
+                                _ => 0,
+                            })
+                            .max()
+                            .unwrap_or(0);
+                        // Oracle can't turn a VARCHAR2 into a CLOB in place
+                        if longest > ORACLE_VARCHAR_LIMIT && is_oracle {
+                            return Err(Error::VarcharLimit(existing.name.to_string(), longest));
+                        }
+                        if longest > size {
+                            widened.push(AlterColumns {
+                                name: existing.name.to_string(),
+                                data_type: CreateDataTypes::VARCHAR(longest),
+                                default: None,
+                                not_null: false,
+                            });
+                        }
+                    }
+                }
+                Some(_) => (),
+            }
+        }
+
+        if !added.is_empty() {
+            self.connect
+                .to_owned()
+                .alter_initialization()
+                .table(&self.table)
+                .add(added)
+                .build()?;
+        }
+        // SQLite doesn't enforce VARCHAR lengths
+        if !widened.is_empty() && is_oracle {
+            self.connect
+                .to_owned()
+                .alter_initialization()
+                .table(&self.table)
+                .modify(widened)
+                .build()?;
+        }
+        Ok(self)
+    }
+}
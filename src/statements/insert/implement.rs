@@ -1,12 +1,34 @@
 use crate::{
     Error, SQLImplementation,
-    statements::insert::sql_implementations::{
-        oracle::oracle_build_insert,
-        sqlite::{sqlite_build_insert, sqlite_build_insert_pb},
+    data_types::SQLDataTypes,
+    schema::{oracle::oracle_query_columns, sqlite::sqlite_query_columns},
+    statements::{
+        create::{
+            CreateColumns, CreateDataTypes, CreateTable, ModifyCreateTable,
+            sql_implementations::utils::ORACLE_VARCHAR_LIMIT,
+        },
+        insert::{
+            data_conversion::{epoch_conversion, format_grid},
+            sql_implementations::{
+                oracle::{
+                    oracle_build_insert, oracle_build_insert_from, oracle_build_insert_returning,
+                    validation::get_col_indexes,
+                },
+                sqlite::{
+                    sqlite_build_insert, sqlite_build_insert_from, sqlite_build_insert_returning,
+                },
+            },
+        },
     },
+    transfer::ChunkWriter,
+    utils::remove_invalid_chars,
 };
 
-use super::{InsertBuilder, InsertProps, InsertPropsFormatted};
+use super::{
+    Checkpoint, ConflictAction, DateOrder, ErrorPolicy, FailedRow, InsertBuilder, InsertFromProps,
+    InsertIterProps, InsertOnError, InsertProps, InsertPropsFormatted, InsertReport,
+    InsertReturning, OnConflict, ParseOptions, ValidationReport,
+};
 
 impl InsertPropsFormatted {
     pub fn build(self) -> Result<(), Error> {
@@ -16,35 +38,557 @@ impl InsertPropsFormatted {
     pub fn build_with_progress_bar(self) -> Result<(), Error> {
         self.insert_props.build_with_progress_bar()
     }
+
+    pub fn on_conflict(mut self, keys: &[&str], action: ConflictAction) -> Self {
+        self.insert_props = self.insert_props.on_conflict(keys, action);
+        self
+    }
+
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        self.insert_props = self.insert_props.chunk_size(chunk_size);
+        self
+    }
+
+    pub fn checkpoint(mut self, checkpoint: Checkpoint) -> Self {
+        self.insert_props = self.insert_props.checkpoint(checkpoint);
+        self
+    }
+
+    pub fn resume(mut self) -> Self {
+        self.insert_props = self.insert_props.resume();
+        self
+    }
+
+    pub fn coerce_to_table_types(mut self) -> Self {
+        self.insert_props = self.insert_props.coerce_to_table_types();
+        self
+    }
+
+    pub fn evolve_schema(mut self) -> Self {
+        self.insert_props = self.insert_props.evolve_schema();
+        self
+    }
+
+    pub fn returning(self, columns: &[&str]) -> InsertReturning {
+        self.insert_props.returning(columns)
+    }
+
+    pub fn on_error(self, policy: ErrorPolicy) -> InsertOnError {
+        self.insert_props.on_error(policy)
+    }
+
+    pub fn validate(&self) -> Result<ValidationReport, Error> {
+        self.insert_props.validate()
+    }
+
+    pub fn validate_against(&self, create_table: &CreateTable) -> ValidationReport {
+        self.insert_props.validate_against(create_table)
+    }
+
+    pub fn last_insert_rowid(self) -> Result<i64, Error> {
+        self.insert_props.last_insert_rowid()
+    }
+}
+
+impl InsertReturning {
+    /// Builds the query and returns a row of the returning columns for each inserted row.
+    pub fn build(mut self) -> Result<Vec<Vec<SQLDataTypes>>, Error> {
+        self.insert_props = self
+            .insert_props
+            .map_grid_columns()?
+            .evolve_table()?
+            .coerce_grid()?;
+        match self.insert_props.connect {
+            SQLImplementation::Oracle(_) => oracle_build_insert_returning(self),
+            SQLImplementation::SQLite(_) => sqlite_build_insert_returning(self),
+        }
+    }
+}
+
+impl InsertOnError {
+    /// Builds the query and returns what was inserted and which rows failed.
+    pub fn build(self) -> Result<InsertReport, Error> {
+        let insert_props = self
+            .insert_props
+            .map_grid_columns()?
+            .evolve_table()?
+            .coerce_grid()?;
+        match insert_props.connect {
+            SQLImplementation::Oracle(_) => oracle_build_insert(insert_props, false, self.policy),
+            SQLImplementation::SQLite(_) => sqlite_build_insert(insert_props, false, self.policy),
+        }
+    }
+
+    /// Builds the query with a progress bar and returns what was inserted and which rows failed.
+    pub fn build_with_progress_bar(self) -> Result<InsertReport, Error> {
+        let insert_props = self
+            .insert_props
+            .map_grid_columns()?
+            .evolve_table()?
+            .coerce_grid()?;
+        match insert_props.connect {
+            SQLImplementation::Oracle(_) => oracle_build_insert(insert_props, true, self.policy),
+            SQLImplementation::SQLite(_) => sqlite_build_insert(insert_props, true, self.policy),
+        }
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            date_order: DateOrder::MonthFirst,
+            formats: Vec::new(),
+            decimal_separator: '.',
+            thousands_separator: None,
+            currency_symbols: Vec::new(),
+            percentages: false,
+            accounting_negatives: false,
+            null_tokens: Vec::new(),
+            keep_leading_zeros: false,
+            epoch_columns: Vec::new(),
+        }
+    }
+}
+
+impl ParseOptions {
+    pub fn new() -> Self {
+        ParseOptions::default()
+    }
+
+    pub fn date_order(mut self, date_order: DateOrder) -> Self {
+        self.date_order = date_order;
+        self
+    }
+
+    /// Tries this chrono pattern before the built-in date parsers. Patterns are tried in the order they're added.
+    pub fn format(mut self, format: &str) -> Self {
+        self.formats.push(format.to_string());
+        self
+    }
+
+    /// Sets the decimal and thousands separators, e.g. `(',', Some('.'))` for `1.234,5`.
+    pub fn separators(mut self, decimal: char, thousands: Option<char>) -> Self {
+        self.decimal_separator = decimal;
+        self.thousands_separator = thousands;
+        self
+    }
+
+    pub fn currency_symbols(mut self, symbols: &[&str]) -> Self {
+        self.currency_symbols = symbols.iter().map(|symbol| symbol.to_string()).collect();
+        self
+    }
+
+    pub fn percentages(mut self) -> Self {
+        self.percentages = true;
+        self
+    }
+
+    pub fn accounting_negatives(mut self) -> Self {
+        self.accounting_negatives = true;
+        self
+    }
+
+    pub fn null_tokens(mut self, tokens: &[&str]) -> Self {
+        self.null_tokens = tokens.iter().map(|token| token.to_string()).collect();
+        self
+    }
+
+    pub fn keep_leading_zeros(mut self) -> Self {
+        self.keep_leading_zeros = true;
+        self
+    }
+
+    pub fn epoch_columns(mut self, columns: &[&str]) -> Self {
+        self.epoch_columns = columns.iter().map(|column| column.to_string()).collect();
+        self
+    }
+}
+
+impl InsertReport {
+    /// Records a failed row according to the policy, or returns it as an error with [`ErrorPolicy::Abort`].
+    pub(crate) fn row_failed(
+        &mut self,
+        policy: ErrorPolicy,
+        index: usize,
+        values: &[SQLDataTypes],
+        message: String,
+    ) -> Result<(), Error> {
+        match policy {
+            ErrorPolicy::Abort => return Err(Error::RowInsertError(index, message)),
+            ErrorPolicy::Skip => (),
+            ErrorPolicy::Collect => self.failed.push(FailedRow {
+                index,
+                values: values.to_vec(),
+                message,
+            }),
+        }
+        self.skipped += 1;
+        Ok(())
+    }
+
+    pub(crate) fn merge(&mut self, other: InsertReport) {
+        self.inserted += other.inserted;
+        self.skipped += other.skipped;
+        self.failed.extend(other.failed);
+        self.failed.sort_by_key(|failed| failed.index);
+    }
+}
+
+impl OnConflict {
+    /// Matches the key and update columns to the insert's header and returns them as `(keys, columns to update)`.
+    pub(crate) fn resolve(&self, header: &[String]) -> Result<(Vec<String>, Vec<String>), Error> {
+        if self.keys.is_empty() {
+            return Err(Error::NoConflictKeys);
+        }
+        let find = |name: &String| -> Result<String, Error> {
+            header
+                .iter()
+                .find(|head| head.eq_ignore_ascii_case(name))
+                .cloned()
+                .ok_or(Error::ColumnDoesNotExist(name.to_string()))
+        };
+        let keys = self
+            .keys
+            .iter()
+            .map(find)
+            .collect::<Result<Vec<String>, Error>>()?;
+        let update = match &self.action {
+            // Oracle can't update the columns a MERGE joins on, and they already match anyway
+            ConflictAction::Update(columns) => columns
+                .iter()
+                .map(find)
+                .collect::<Result<Vec<String>, Error>>()?
+                .into_iter()
+                .filter(|column| !keys.contains(column))
+                .collect(),
+            ConflictAction::Ignore => Vec::new(),
+            ConflictAction::Replace => header
+                .iter()
+                .filter(|head| !keys.contains(head))
+                .cloned()
+                .collect(),
+        };
+        Ok((keys, update))
+    }
 }
 
 impl InsertBuilder for InsertProps {
-    fn format_grid_strings(mut self) -> Result<InsertPropsFormatted, Error> {
-        for y_idx in 0..self.grid.len() {
-            for x_idx in 0..self.grid[y_idx].len() {
-                self.grid[y_idx][x_idx].format_data_types();
+    fn format_grid_strings(self) -> Result<InsertPropsFormatted, Error> {
+        self.format_grid_strings_with(ParseOptions::default())
+    }
+
+    fn format_grid_strings_with(
+        mut self,
+        options: ParseOptions,
+    ) -> Result<InsertPropsFormatted, Error> {
+        let epoch_columns = self
+            .header
+            .iter()
+            .enumerate()
+            .filter(|(_, head)| {
+                options.epoch_columns.iter().any(|column| {
+                    head.eq_ignore_ascii_case(column)
+                        || head.eq_ignore_ascii_case(&remove_invalid_chars(column))
+                })
+            })
+            .map(|(idx, _)| idx)
+            .collect::<Vec<usize>>();
+        format_grid(&mut self.grid, &options);
+        for row in self.grid.iter_mut() {
+            for (x_idx, cell) in row.iter_mut().enumerate() {
+                if epoch_columns.contains(&x_idx) {
+                    epoch_conversion(cell);
+                }
             }
         }
 
+        self.parse_options = options;
         Ok(InsertPropsFormatted { insert_props: self })
     }
 
     fn build(self) -> Result<(), Error> {
-        match self.connect {
-            SQLImplementation::Oracle(_) => oracle_build_insert(self, false),
-            SQLImplementation::SQLite(_) => sqlite_build_insert(self),
-        }
+        self.on_error(ErrorPolicy::Abort).build()?;
+        Ok(())
     }
 
     fn build_with_progress_bar(self) -> Result<(), Error> {
+        self.on_error(ErrorPolicy::Abort)
+            .build_with_progress_bar()?;
+        Ok(())
+    }
+
+    fn create_table(mut self) -> Self {
+        self.create = true;
+        self
+    }
+
+    fn on_error(self, policy: ErrorPolicy) -> InsertOnError {
+        InsertOnError {
+            insert_props: self,
+            policy,
+        }
+    }
+
+    fn returning(self, columns: &[&str]) -> InsertReturning {
+        InsertReturning {
+            insert_props: self,
+            columns: columns.iter().map(|col| col.to_string()).collect(),
+        }
+    }
+
+    fn last_insert_rowid(self) -> Result<i64, Error> {
+        if let SQLImplementation::Oracle(_) = self.connect {
+            return Err(Error::SQLVariationError);
+        }
+        let rows = self.returning(&["rowid"]).build()?;
+        match rows.last().and_then(|row| row.first()) {
+            Some(SQLDataTypes::Number(rowid)) => Ok(*rowid),
+            _ => Err(Error::NoData),
+        }
+    }
+
+    fn columns(mut self, columns: &[&str]) -> Self {
+        self.header = columns.iter().map(|col| col.to_string()).collect();
+        self
+    }
+
+    fn map_column(mut self, grid_column: &str, table_column: &str) -> Self {
+        self.column_map
+            .push((grid_column.to_string(), table_column.to_string()));
+        self
+    }
+
+    fn chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    fn checkpoint(mut self, checkpoint: Checkpoint) -> Self {
+        self.checkpoint = Some(checkpoint);
+        self
+    }
+
+    fn resume(mut self) -> Self {
+        self.resume = true;
+        self
+    }
+
+    fn coerce_to_table_types(mut self) -> Self {
+        self.coerce = true;
+        self
+    }
+
+    fn evolve_schema(mut self) -> Self {
+        self.evolve = true;
+        self
+    }
+
+    fn on_conflict(mut self, keys: &[&str], action: ConflictAction) -> Self {
+        self.on_conflict = Some(OnConflict {
+            keys: keys.iter().map(|key| key.to_string()).collect(),
+            action,
+        });
+        self
+    }
+}
+
+impl InsertFromProps {
+    /// Will create the target table from the columns the select returns if it does not exist.
+    pub fn create_table(mut self) -> Self {
+        self.create = true;
+        self
+    }
+
+    pub fn build(self) -> Result<(), Error> {
         match self.connect {
-            SQLImplementation::Oracle(_) => oracle_build_insert(self, true),
-            SQLImplementation::SQLite(_) => sqlite_build_insert_pb(self),
+            SQLImplementation::Oracle(_) => oracle_build_insert_from(self),
+            SQLImplementation::SQLite(_) => sqlite_build_insert_from(self),
         }
     }
 
-    fn create_table(mut self) -> Self {
+    pub(crate) fn create_target(&self, select: &str) -> Result<(), Error> {
+        let result_columns = match &self.connect {
+            SQLImplementation::Oracle(connect) => oracle_query_columns(connect, select)?,
+            SQLImplementation::SQLite(connect) => sqlite_query_columns(connect, select)?,
+        };
+        if !self.columns.is_empty() && self.columns.len() != result_columns.len() {
+            return Err(Error::ColumnCountMismatch(
+                self.columns.len(),
+                result_columns.len(),
+            ));
+        }
+        let columns = result_columns
+            .into_iter()
+            .enumerate()
+            .map(|(idx, (name, data_type))| {
+                let name = self.columns.get(idx).unwrap_or(&name);
+                CreateColumns::new(name, data_type)
+            })
+            .collect::<Vec<CreateColumns>>();
+        self.connect
+            .clone()
+            .create_initialization()
+            .table(&self.table, columns)
+            .build()
+    }
+
+    pub(crate) fn to_sql(&self, select: &str) -> String {
+        match self.columns.is_empty() {
+            true => format!("INSERT INTO {} {}", self.table, select),
+            false => format!(
+                "INSERT INTO {} ({}) {}",
+                self.table,
+                self.columns.join(", "),
+                select
+            ),
+        }
+    }
+}
+
+impl InsertIterProps<'_> {
+    /// Number of rows bound and committed at a time. Defaults to 10,000.
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Will create the table from the types in the first batch if it does not exist.
+    ///
+    /// Later batches aren't seen before the table is created, so nothing is sized from the first batch.
+    /// Decimal columns are an unconstrained `NUMBER` on Oracle (and text on SQLite), so a later batch's
+    /// extra decimal places aren't rounded away. Text columns, and columns that are empty in the first batch,
+    /// are created as `VARCHAR2(4000)`, the largest Oracle allows, since a `VARCHAR2` can't be widened into a `CLOB`
+    /// once rows are in it. A later batch that needs a different type or longer text fails,
+    /// so create the table beforehand when the first batch isn't representative.
+    pub fn create_table(mut self) -> Self {
         self.create = true;
         self
     }
+
+    /// Consumes the iterator and returns the number of rows inserted.
+    /// Each batch is committed on its own, so a failed insert leaves the rows from the batches before it.
+    pub fn build(mut self) -> Result<usize, Error> {
+        let width = self.header.len();
+        let mut writer: Option<ChunkWriter> = None;
+        let mut inserted = 0;
+        loop {
+            let mut batch = self
+                .rows
+                .by_ref()
+                .take(self.batch_size)
+                .collect::<Vec<Vec<SQLDataTypes>>>();
+            if batch.is_empty() {
+                break;
+            }
+            if let Some(row) = batch.iter().find(|row| row.len() > width) {
+                return Err(Error::ColumnCountMismatch(width, row.len()));
+            }
+            batch
+                .iter_mut()
+                .for_each(|row| row.resize(width, SQLDataTypes::NULL));
+
+            if writer.is_none() {
+                writer = Some(self.writer(&batch)?);
+            }
+            inserted += batch.len();
+            if let Some(writer) = &mut writer {
+                writer.write(batch)?;
+            }
+        }
+        Ok(inserted)
+    }
+
+    fn writer(&self, first_batch: &Vec<Vec<SQLDataTypes>>) -> Result<ChunkWriter, Error> {
+        let schema = match self.connect.describe_table(&self.table) {
+            Ok(schema) => schema,
+            Err(Error::TableDoesNotExist) if self.create => {
+                let mut columns = get_col_indexes(first_batch)?.to_create_columns(&self.header);
+                // Sized for the batches still to come, not just the first one
+                for column in columns.iter_mut() {
+                    column.data_type = match column.data_type {
+                        CreateDataTypes::VARCHAR(_) => {
+                            CreateDataTypes::VARCHAR(ORACLE_VARCHAR_LIMIT)
+                        }
+                        // An unconstrained NUMBER keeps every decimal place on Oracle,
+                        // and SQLite stores a 38 digit NUMBER as text
+                        CreateDataTypes::NUMBER(precision, scale) if precision > 0 => {
+                            match self.connect {
+                                SQLImplementation::Oracle(_) => CreateDataTypes::NUMBER(0, 0),
+                                SQLImplementation::SQLite(_) => CreateDataTypes::NUMBER(38, scale),
+                            }
+                        }
+                        ref data_type => data_type.clone(),
+                    };
+                }
+                self.connect
+                    .clone()
+                    .create_initialization()
+                    .table(&self.table, columns)
+                    .build()?;
+                self.connect.describe_table(&self.table)?
+            }
+            Err(err) => return Err(err),
+        };
+        let columns = self
+            .header
+            .iter()
+            .map(|head| {
+                schema
+                    .columns
+                    .iter()
+                    .find(|column| column.name.eq_ignore_ascii_case(head))
+                    .cloned()
+                    .ok_or(Error::ColumnDoesNotExist(head.to_string()))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        ChunkWriter::new(&self.connect, &self.table, &self.header, &columns)
+    }
+}
+
+impl InsertProps {
+    /// Applies the [`map_column`](InsertBuilder::map_column) pairs, keeping only the mapped grid columns
+    /// and renaming them to their table columns. Also checks every row fits the header, padding short rows with NULL.
+    fn map_grid_columns(mut self) -> Result<Self, Error> {
+        if !self.column_map.is_empty() {
+            let indices = self
+                .column_map
+                .iter()
+                .map(|(grid_column, _)| {
+                    let cleaned = remove_invalid_chars(grid_column);
+                    self.header
+                        .iter()
+                        .position(|head| {
+                            head.eq_ignore_ascii_case(grid_column)
+                                || head.eq_ignore_ascii_case(&cleaned)
+                        })
+                        .ok_or(Error::ColumnDoesNotExist(grid_column.to_string()))
+                })
+                .collect::<Result<Vec<usize>, Error>>()?;
+            self.grid = self
+                .grid
+                .into_iter()
+                .map(|row| {
+                    indices
+                        .iter()
+                        .map(|idx| row.get(*idx).cloned().unwrap_or(SQLDataTypes::NULL))
+                        .collect()
+                })
+                .collect();
+            self.header = self
+                .column_map
+                .iter()
+                .map(|(_, table_column)| table_column.to_string())
+                .collect();
+            self.column_map = Vec::new();
+        }
+
+        if let Some(row) = self.grid.iter().find(|row| row.len() > self.header.len()) {
+            return Err(Error::ColumnCountMismatch(self.header.len(), row.len()));
+        }
+        let width = self.header.len();
+        self.grid
+            .iter_mut()
+            .for_each(|row| row.resize(width, SQLDataTypes::NULL));
+        Ok(self)
+    }
 }
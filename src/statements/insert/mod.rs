@@ -1,8 +1,16 @@
-use crate::{Error, SQLImplementation, data_types::SQLDataTypes};
+use crate::{
+    Error, SQLImplementation,
+    data_types::SQLDataTypes,
+    statements::{create::CreateDataTypes, select::SelectProps},
+};
 
+pub(crate) mod checkpoint;
+pub(crate) mod coerce;
 pub mod data_conversion;
+pub(crate) mod evolve;
 pub mod implement;
 pub mod sql_implementations;
+pub(crate) mod validate;
 
 #[derive(Debug)]
 pub struct InsertProps {
@@ -11,14 +19,202 @@ pub struct InsertProps {
     pub grid: Vec<Vec<SQLDataTypes>>,
     pub table: String,
     pub create: bool,
+    pub on_conflict: Option<OnConflict>,
+    /// Pairs of (grid column, table column) set with [`map_column`](InsertBuilder::map_column).
+    pub column_map: Vec<(String, String)>,
+    /// Number of rows per transaction on SQLite, and per batch on both databases when checkpointing. Defaults to 10,000.
+    pub chunk_size: usize,
+    pub checkpoint: Option<Checkpoint>,
+    /// Skips the batches the [`Checkpoint`] says were already committed.
+    pub resume: bool,
+    /// Converts the grid to the existing table's column types before inserting.
+    pub coerce: bool,
+    /// Adds missing columns and widens `VARCHAR2` columns of the existing table before inserting.
+    pub evolve: bool,
+    /// The [`ParseOptions`] the grid was formatted with, which coercion also parses text with.
+    pub parse_options: ParseOptions,
+}
+
+/// An insert that returns columns of the inserted rows, created with [`returning`](InsertBuilder::returning).
+#[derive(Debug)]
+pub struct InsertReturning {
+    pub insert_props: InsertProps,
+    pub columns: Vec<String>,
+}
+
+/// An insert that handles failed rows with an [`ErrorPolicy`], created with [`on_error`](InsertBuilder::on_error).
+#[derive(Debug)]
+pub struct InsertOnError {
+    pub insert_props: InsertProps,
+    pub policy: ErrorPolicy,
+}
+
+/// What happens when a row fails to insert, like a constraint violation or a value too large for its column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErrorPolicy {
+    /// Stops at the first failed row and returns [`Error::RowInsertError`] with its index.
+    #[default]
+    Abort,
+    /// Inserts the rest of the rows and only counts the ones that failed.
+    Skip,
+    /// Inserts the rest of the rows and keeps the ones that failed in the [`InsertReport`].
+    Collect,
+}
+
+/// What an insert built with an [`ErrorPolicy`] did.
+#[derive(Debug, Clone, Default)]
+pub struct InsertReport {
+    /// Rows written to the table. Rows an upsert ignored aren't counted.
+    pub inserted: usize,
+    /// Number of rows that failed, whether or not they were collected.
+    pub skipped: usize,
+    /// The failed rows, only kept with [`ErrorPolicy::Collect`].
+    pub failed: Vec<FailedRow>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FailedRow {
+    /// Index of the row in the grid, not counting the header.
+    pub index: usize,
+    pub values: Vec<SQLDataTypes>,
+    pub message: String,
+}
+
+/// Inserts the rows of a select query, created with [`insert_from`](crate::QueryBuilder::insert_from).
+#[derive(Debug)]
+pub struct InsertFromProps {
+    pub connect: SQLImplementation,
+    pub table: String,
+    /// The target columns, in the same order as the select's columns. Inserts into every column when empty.
+    pub columns: Vec<String>,
+    pub select: SelectProps,
+    pub create: bool,
+}
+
+/// Streams rows from an iterator into a table in batches, created with [`insert_iter`](crate::QueryBuilder::insert_iter).
+pub struct InsertIterProps<'a> {
+    pub connect: SQLImplementation,
+    pub table: String,
+    pub header: Vec<String>,
+    pub rows: Box<dyn Iterator<Item = Vec<SQLDataTypes>> + 'a>,
+    /// Number of rows bound and committed at a time.
+    pub batch_size: usize,
+    pub create: bool,
+}
+
+/// Where a [`checkpoint`](InsertBuilder::checkpoint)ed insert records the batches it has committed.
+#[derive(Debug, Clone)]
+pub enum Checkpoint {
+    /// A text file with a line for each committed batch. It's written right after the batch is committed.
+    File(String),
+    /// A table in the same database, created if it doesn't exist.
+    /// Each batch's checkpoint is committed in the same transaction as its rows.
+    Table(String),
+}
+
+/// Problems [`validate`](InsertProps::validate) found between the grid and the table, before anything is written.
+#[derive(Debug, Clone, Default)]
+pub struct ValidationReport {
+    pub issues: Vec<ValidationIssue>,
+}
+
+/// Rows are indexed from the first row of the grid, not counting the header.
+#[derive(Debug, Clone)]
+pub enum ValidationIssue {
+    /// The grid has a column the table doesn't.
+    UnknownColumn { column: String },
+    /// A `NOT NULL` column without a default isn't in the grid.
+    MissingColumn { column: String },
+    /// A NULL (or empty text on Oracle, which stores it as NULL) going into a `NOT NULL` column.
+    NullValue { row: usize, column: String },
+    /// Text longer than the column's declared length, counted in bytes.
+    VarcharOverflow {
+        row: usize,
+        column: String,
+        max: usize,
+        length: usize,
+    },
+    /// Text going into a date, time or timestamp column that none of the date parsers understand.
+    UnparseableDate {
+        row: usize,
+        column: String,
+        value: String,
+    },
+    /// A value that can't be stored in the column's type, like text in a `NUMBER` column.
+    TypeConflict {
+        row: usize,
+        column: String,
+        expected: CreateDataTypes,
+        value: SQLDataTypes,
+    },
+}
+
+/// Rules for [`format_grid_strings_with`](InsertBuilder::format_grid_strings_with).
+/// The defaults parse the same way as [`format_grid_strings`](InsertBuilder::format_grid_strings).
+#[derive(Debug, Clone)]
+pub struct ParseOptions {
+    pub date_order: DateOrder,
+    /// [chrono patterns](https://docs.rs/chrono/latest/chrono/format/strftime/index.html) tried before the built-in date parsers,
+    /// e.g. `%d.%m.%Y` or `%Y%m%d %H%M`. A pattern with an offset (`%z`) gives a `TimestampTz`.
+    pub formats: Vec<String>,
+    pub decimal_separator: char,
+    /// Removed from numbers like `1,234.5` when the digits are grouped in threes.
+    pub thousands_separator: Option<char>,
+    /// Stripped from the start or end of a number, e.g. `$` or `€`.
+    pub currency_symbols: Vec<String>,
+    /// Reads `12.5%` as `0.125`.
+    pub percentages: bool,
+    /// Reads `(123)` as `-123`.
+    pub accounting_negatives: bool,
+    /// Text read as NULL, like `N/A` or `-`. Matched ignoring case and surrounding whitespace.
+    pub null_tokens: Vec<String>,
+    /// Keeps text of only digits with a leading zero, like ZIP codes, as text instead of a number.
+    pub keep_leading_zeros: bool,
+    /// Columns of Unix timestamps (seconds since 1970-01-01 UTC) read as a `Timestamp`.
+    pub epoch_columns: Vec<String>,
+}
+
+/// How a date like `01/05/2024` is read. Dates starting with the year aren't affected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateOrder {
+    /// `mm/dd/yyyy`, falling back to `dd/mm/yyyy` when the month would be over 12.
+    #[default]
+    MonthFirst,
+    /// `dd/mm/yyyy`, falling back to `mm/dd/yyyy` when the month would be over 12.
+    DayFirst,
+}
+
+/// The key columns to check and what to do when an inserted row matches an existing one.
+#[derive(Debug, Clone)]
+pub struct OnConflict {
+    pub keys: Vec<String>,
+    pub action: ConflictAction,
+}
+
+#[derive(Debug, Clone)]
+pub enum ConflictAction {
+    /// Updates these columns of the existing row with the inserted values.
+    Update(Vec<String>),
+    /// Keeps the existing row and skips the inserted one.
+    Ignore,
+    /// Overwrites every inserted column (other than the keys) of the existing row.
+    Replace,
 }
 
 #[derive(Debug, Clone)]
 pub struct DatatypeIndices {
     pub is_varchar: Vec<usize>,
+    pub is_json: Vec<usize>,
+    pub is_decimal: Vec<usize>,
     pub is_float: Vec<usize>,
     pub is_int: Vec<usize>,
+    pub is_bool: Vec<usize>,
+    pub is_timestamptz: Vec<usize>,
+    pub is_timestamp: Vec<usize>,
     pub is_date: Vec<usize>,
+    pub is_time: Vec<usize>,
+    pub is_interval: Vec<usize>,
+    pub is_interval_ym: Vec<usize>,
 }
 
 pub struct InsertPropsFormatted {
@@ -29,12 +225,127 @@ pub trait InsertBuilder {
     /// Converts unstructured data into [`SQLDataTypes`].
     fn format_grid_strings(self) -> Result<InsertPropsFormatted, Error>;
 
+    /// Converts unstructured data into [`SQLDataTypes`] with the rules of the [`ParseOptions`],
+    /// for files with day first dates, European number separators, currencies or placeholder NULLs.
+    /// ```no_run
+    /// let options = ParseOptions::new()
+    ///     .date_order(DateOrder::DayFirst)
+    ///     .separators(',', Some('.'))
+    ///     .currency_symbols(&["€"])
+    ///     .null_tokens(&["N/A", "-"])
+    ///     .keep_leading_zeros();
+    /// conn.insert("regional_sales", data)?
+    ///     .format_grid_strings_with(options)?
+    ///     .build()?;
+    /// ```
+    fn format_grid_strings_with(self, options: ParseOptions)
+    -> Result<InsertPropsFormatted, Error>;
+
     /// Will Create the input table if it does not exist.
     fn create_table(self) -> Self;
 
+    /// Replaces the header with the table columns each position of the grid goes into.
+    /// ```no_run
+    /// conn.insert_rows("regional_sales", rows)?
+    ///     .columns(&["city", "revenue"])
+    ///     .build()?;
+    /// ```
+    fn columns(self, columns: &[&str]) -> Self;
+
+    /// Inserts a grid column into a differently named table column.
+    /// Once a column is mapped, any grid column that isn't mapped is skipped.
+    /// ```no_run
+    /// conn.insert("regional_sales", data)?
+    ///     .map_column("City Name", "city")
+    ///     .map_column("Total", "revenue")
+    ///     .build()?;
+    /// ```
+    fn map_column(self, grid_column: &str, table_column: &str) -> Self;
+
+    /// Turns the insert into an upsert. Rows that match an existing row on the key columns are handled by the [`ConflictAction`].
+    /// SQLite uses `INSERT ... ON CONFLICT`, which needs a primary key or unique constraint on the key columns, and Oracle uses `MERGE`.
+    /// If the table gets created by [`create_table`](InsertBuilder::create_table), a unique constraint is added on the key columns.
+    /// Building without any key columns returns [`Error::NoConflictKeys`].
+    fn on_conflict(self, keys: &[&str], action: ConflictAction) -> Self;
+
+    /// Sets how many rows are inserted per transaction on SQLite. Defaults to 10,000.
+    /// Every row is bound to the same prepared statement, and each chunk is committed on its own.
+    fn chunk_size(self, chunk_size: usize) -> Self;
+
+    /// Records each batch of [`chunk_size`](InsertBuilder::chunk_size) rows as it's committed,
+    /// so a load that dies halfway can be picked up with [`resume`](InsertBuilder::resume) instead of starting over.
+    /// On Oracle, each thread commits its rows a batch at a time instead of all at once.
+    ///
+    /// Without `resume`, the checkpoints from an earlier run of the same input are cleared and every row is inserted.
+    /// ```no_run
+    /// conn.insert("regional_sales", data)?
+    ///     .checkpoint(Checkpoint::Table("load_checkpoints".to_string()))
+    ///     .resume()
+    ///     .build()?;
+    /// ```
+    fn checkpoint(self, checkpoint: Checkpoint) -> Self;
+
+    /// Skips the batches that the [`checkpoint`](InsertBuilder::checkpoint) recorded as committed on an earlier run.
+    /// Batches are only skipped when the table, header, chunk size and rows are the same as that run.
+    fn resume(self) -> Self;
+
+    /// Converts each cell to the type of its column in the existing table instead of relying on the database's implicit conversions,
+    /// e.g. `"0042"` becomes `42` in a `NUMBER` column and `42` becomes `"42"` in a `VARCHAR2`.
+    /// Text is parsed into numbers, dates and timestamps with the same parsers as [`format_grid_strings`](InsertBuilder::format_grid_strings).
+    ///
+    /// Nothing is inserted if any cell can't be converted. [`Error::CoercionError`] lists every one of them.
+    /// Has no effect when the table doesn't exist yet.
+    /// ```no_run
+    /// match conn.insert("regional_sales", data)?.coerce_to_table_types().build() {
+    ///     Err(Error::CoercionError(issues)) => issues.iter().for_each(|issue| println!("{:?}", issue)),
+    ///     result => result?,
+    /// }
+    /// ```
+    fn coerce_to_table_types(self) -> Self;
+
+    /// Alters the existing table to fit the grid before inserting. Grid columns the table doesn't have are added
+    /// (as nullable columns, with the types [`create_table`](InsertBuilder::create_table) would give them),
+    /// and `VARCHAR2` columns are widened to the longest text going into them.
+    /// SQLite doesn't enforce `VARCHAR` lengths, so only columns are added there.
+    /// ```no_run
+    /// conn.insert("regional_sales", data)?
+    ///     .format_grid_strings()?
+    ///     .evolve_schema()
+    ///     .build()?;
+    /// ```
+    fn evolve_schema(self) -> Self;
+
+    /// Sets what happens when a row fails to insert. The [`InsertOnError`] builds into an [`InsertReport`]
+    /// with the inserted count and the failed rows.
+    ///
+    /// Oracle executes each thread's rows with batch errors, so the rows that fail are left out and the rest are committed.
+    /// SQLite checks each row on its own. With [`ErrorPolicy::Abort`], the rows already committed
+    /// (other Oracle threads, or earlier SQLite chunks) stay in the table.
+    /// ```no_run
+    /// let report = conn.insert("orders", data)?
+    ///     .on_error(ErrorPolicy::Collect)
+    ///     .build()?;
+    /// for failed in report.failed {
+    ///     println!("row {}: {}", failed.index, failed.message);
+    /// }
+    /// ```
+    fn on_error(self, policy: ErrorPolicy) -> InsertOnError;
+
     /// Builds the query.
     fn build(self) -> Result<(), Error>;
 
     /// Builds the query and uses [indicatif](`indicatif::ProgressBar`) to add a progress bar to the terminal.
     fn build_with_progress_bar(self) -> Result<(), Error>;
+
+    /// Returns these columns (like generated IDs) for every inserted row when the [`InsertReturning`] is built.
+    /// SQLite uses `RETURNING` and Oracle uses `RETURNING ... INTO`. Rows are inserted one at a time to get their values back.
+    /// ```no_run
+    /// let ids = conn.insert("orders", data)?
+    ///     .returning(&["order_id"])
+    ///     .build()?;
+    /// ```
+    fn returning(self, columns: &[&str]) -> InsertReturning;
+
+    /// Builds the query and returns the rowid of the last inserted row. SQLite only.
+    fn last_insert_rowid(self) -> Result<i64, Error>;
 }
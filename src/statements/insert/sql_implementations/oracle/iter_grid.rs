@@ -1,40 +1,70 @@
-use std::sync::Arc;
+use std::{str::FromStr, sync::Arc};
 
-use chrono::NaiveDateTime;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeDelta};
 use indicatif::ProgressBar;
-use oracle::Batch;
+use oracle::{Batch, sql_type::IntervalYM};
 
 use crate::{Error, data_types::SQLDataTypes, statements::insert::DatatypeIndices};
 
 use super::sql_fmt::bind_cell_to_batch;
 
 pub fn iter_grid(
-    mut batch: &mut Batch<'_>,
+    batch: &mut Batch<'_>,
     data: Vec<Vec<SQLDataTypes>>,
     progress_bar: Arc<ProgressBar>,
     datatype_indices: DatatypeIndices,
     use_pb: bool,
+) -> Result<(), Error> {
+    bind_grid(batch, &data, &progress_bar, &datatype_indices, use_pb)?;
+    batch.execute()?;
+    Ok(())
+}
+
+/// Appends every row to the batch without executing it.
+pub(crate) fn bind_grid(
+    mut batch: &mut Batch<'_>,
+    data: &[Vec<SQLDataTypes>],
+    progress_bar: &ProgressBar,
+    datatype_indices: &DatatypeIndices,
+    use_pb: bool,
 ) -> Result<(), Error> {
     data.iter().try_for_each(|row| -> Result<(), Error> {
         row.iter()
             .enumerate()
             .try_for_each(|(idx, cell)| -> Result<(), Error> {
                 if let &SQLDataTypes::NULL = cell {
-                    if datatype_indices.is_varchar.contains(&idx) {
-                        return bind_cell_to_batch(&mut batch, &None::<String>, idx);
-                    } else if datatype_indices.is_float.contains(&idx) {
-                        return bind_cell_to_batch(&mut batch, &None::<f64>, idx);
-                    } else if datatype_indices.is_int.contains(&idx) {
-                        return bind_cell_to_batch(&mut batch, &None::<i64>, idx);
+                    if datatype_indices.is_float.contains(&idx) {
+                        bind_cell_to_batch(batch, &None::<f64>, idx)
+                    } else if datatype_indices.is_int.contains(&idx)
+                        || datatype_indices.is_bool.contains(&idx)
+                    {
+                        bind_cell_to_batch(batch, &None::<i64>, idx)
+                    } else if datatype_indices.is_timestamptz.contains(&idx) {
+                        bind_cell_to_batch(batch, &None::<DateTime<FixedOffset>>, idx)
+                    } else if datatype_indices.is_timestamp.contains(&idx) {
+                        bind_cell_to_batch(batch, &None::<NaiveDateTime>, idx)
                     } else if datatype_indices.is_date.contains(&idx) {
-                        return bind_cell_to_batch(&mut batch, &None::<NaiveDateTime>, idx);
+                        bind_cell_to_batch(batch, &None::<NaiveDate>, idx)
+                    } else if datatype_indices.is_interval.contains(&idx) {
+                        bind_cell_to_batch(batch, &None::<TimeDelta>, idx)
+                    } else if datatype_indices.is_interval_ym.contains(&idx) {
+                        bind_cell_to_batch(batch, &None::<IntervalYM>, idx)
                     } else {
-                        return bind_cell_to_batch(&mut batch, &None::<String>, idx);
+                        // VARCHAR2, JSON, exact decimals and times are all bound as text
+                        bind_cell_to_batch(batch, &None::<String>, idx)
                     }
                 } else if datatype_indices.is_varchar.contains(&idx) {
                     let buffer = cell.to_string();
                     let new_val = SQLDataTypes::Varchar(buffer);
                     bind_cell_to_batch(&mut batch, &new_val, idx)
+                } else if datatype_indices.is_decimal.contains(&idx) {
+                    // Binds every cell in the column the same way so Oracle doesn't round it through f64
+                    let new_val = match BigDecimal::from_str(&cell.to_string()) {
+                        Ok(val) => SQLDataTypes::Decimal(val),
+                        Err(_) => cell.to_owned(),
+                    };
+                    bind_cell_to_batch(batch, &new_val, idx)
                 } else {
                     bind_cell_to_batch(&mut batch, cell, idx)
                 }
@@ -44,9 +74,7 @@ pub fn iter_grid(
             progress_bar.inc(1u64);
         }
         Ok(())
-    })?;
-    batch.execute()?;
-    Ok(())
+    })
 }
 
 pub fn divide_grid(grid: &mut Vec<Vec<SQLDataTypes>>, num: f32) -> Vec<Vec<SQLDataTypes>> {
@@ -1,20 +1,28 @@
 use std::{
+    collections::HashSet,
     sync::Arc,
     thread::{self, JoinHandle},
 };
 
 use indicatif::ProgressBar;
-use iter_grid::{divide_grid, iter_grid};
-use sql_fmt::insert_stmt;
+use iter_grid::{bind_grid, divide_grid};
+use sql_fmt::{insert_stmt, merge_stmt};
 use validation::{does_table_exist, get_col_indexes};
 
 use crate::{
     Error, QueryBuilder, SQLImplementation,
     data_types::SQLDataTypes,
-    sql_implementations::utils::get_dt_indices,
+    sql_implementations::{
+        OracleConnect,
+        oracle::{fmt_returning_into, returned_rows, returning_binds},
+        utils::get_dt_indices,
+    },
     statements::{
-        create::{CreateColumns, CreateDataTypes, ModifyCreateTable},
-        insert::InsertProps,
+        create::ModifyCreateTable,
+        insert::{
+            DatatypeIndices, ErrorPolicy, InsertFromProps, InsertProps, InsertReport,
+            InsertReturning, checkpoint::CheckpointLog,
+        },
     },
 };
 
@@ -25,55 +33,42 @@ pub mod validation;
 pub(crate) fn oracle_build_insert(
     mut insert_props: InsertProps,
     use_pb: bool,
-) -> Result<(), Error> {
-    let conn_info = match insert_props.connect {
-        SQLImplementation::Oracle(oracle_connect) => oracle_connect,
+    policy: ErrorPolicy,
+) -> Result<InsertReport, Error> {
+    let conn_info = match &insert_props.connect {
+        SQLImplementation::Oracle(oracle_connect) => oracle_connect.to_owned(),
         SQLImplementation::SQLite(_) => return Err(Error::SQLVariationError),
     };
     let username_conn = conn_info.username.to_owned();
     let password_conn = conn_info.password.to_owned();
     let connection_string_conn = conn_info.connection_string.to_owned();
 
+    let (query, keys) = match &insert_props.on_conflict {
+        Some(on_conflict) => {
+            let (keys, update) = on_conflict.resolve(&insert_props.header)?;
+            let query = merge_stmt(&insert_props.table, &insert_props.header, &keys, &update);
+            (query, keys)
+        }
+        None => {
+            let query = insert_stmt(
+                insert_props.header.len(),
+                &insert_props.table,
+                &insert_props.header.join(", "),
+            );
+            (query, Vec::new())
+        }
+    };
+
     let table_exist = does_table_exist(&insert_props.table, &conn_info)?;
     if !table_exist && insert_props.create {
         let col_type_indexes = get_col_indexes(&insert_props.grid)?;
-        let columns = &insert_props
-            .header
-            .iter()
-            .enumerate()
-            .map(|(idx, cell)| {
-                if col_type_indexes.is_date.contains(&idx) {
-                    CreateColumns {
-                        name: cell.to_string(),
-                        data_type: CreateDataTypes::DATE,
-                    }
-                } else if col_type_indexes.is_int.contains(&idx) {
-                    CreateColumns {
-                        name: cell.to_string(),
-                        data_type: CreateDataTypes::NUMBER,
-                    }
-                } else if col_type_indexes.is_float.contains(&idx) {
-                    CreateColumns {
-                        name: cell.to_string(),
-                        data_type: CreateDataTypes::FLOAT,
-                    }
-                } else {
-                    let size = if let Some(val) = col_type_indexes.varchar_size.get(&idx) {
-                        val
-                    } else {
-                        &(1 as usize)
-                    };
-                    CreateColumns {
-                        name: cell.to_string(),
-                        data_type: CreateDataTypes::VARCHAR(*size),
-                    }
-                }
-            })
-            .collect::<Vec<CreateColumns>>();
-        conn_info
-            .create()
-            .table(&insert_props.table, columns.to_vec())
-            .build()?;
+        let columns = col_type_indexes.to_create_columns(&insert_props.header);
+        let mut create_table = conn_info.create().table(&insert_props.table, columns);
+        if !keys.is_empty() {
+            let keys = keys.iter().map(|key| key.as_str()).collect::<Vec<&str>>();
+            create_table = create_table.unique(&keys);
+        }
+        create_table.build()?;
     } else if !table_exist && !insert_props.create {
         return Err(Error::TableDoesNotExist);
     }
@@ -87,21 +82,40 @@ pub(crate) fn oracle_build_insert(
 
     let datatype_indices = get_dt_indices(&insert_props.grid);
 
-    if len < &nthreads {
-        let query = insert_stmt(
-            insert_props.header.len(),
-            &insert_props.table,
-            &insert_props.header.join(", "),
+    if let Some(checkpoint) = CheckpointLog::open(&insert_props)? {
+        return oracle_insert_checkpointed(
+            &conn_info,
+            &query,
+            insert_props.grid,
+            insert_props.chunk_size,
+            checkpoint,
+            pb,
+            datatype_indices,
+            use_pb,
+            policy,
         );
+    }
+
+    if len < &nthreads {
         let data = insert_props.grid;
         let conn: oracle::Connection =
-            oracle::Connection::connect(username_conn, password_conn, connection_string_conn)
-                .unwrap();
-        let mut batch = conn.batch(&query, data.len()).build()?;
-        iter_grid(&mut batch, data, pb, datatype_indices, use_pb)?;
+            oracle::Connection::connect(username_conn, password_conn, connection_string_conn)?;
+        let report = insert_batch(
+            &conn,
+            &query,
+            data,
+            0,
+            &pb,
+            &datatype_indices,
+            use_pb,
+            policy,
+        )?;
         conn.commit()?;
+        Ok(report)
     } else {
-        let mut handles: Vec<JoinHandle<Result<(), Error>>> = Vec::new();
+        let mut handles: Vec<JoinHandle<Result<InsertReport, Error>>> = Vec::new();
+        // Index of each thread's first row in the grid, to report failed rows by their grid index
+        let mut offset = 0;
         for n in 0..nthreads {
             let data: Vec<Vec<SQLDataTypes>>;
             if n + 1 < nthreads {
@@ -109,11 +123,9 @@ pub(crate) fn oracle_build_insert(
             } else {
                 data = insert_props.grid.to_owned();
             }
-            let query = insert_stmt(
-                insert_props.header.len(),
-                &insert_props.table,
-                &insert_props.header.join(", "),
-            );
+            let thread_offset = offset;
+            offset += data.len();
+            let query = query.clone();
             let username = username_conn.clone();
             let password = password_conn.clone();
             let connection_string = connection_string_conn.clone();
@@ -122,18 +134,250 @@ pub(crate) fn oracle_build_insert(
             handles.push(thread::spawn(move || {
                 // println!("THREAD:{n} DATA:{:?}", data);
                 let conn: oracle::Connection =
-                    oracle::Connection::connect(username, password, connection_string).unwrap();
-                let mut batch = conn.batch(&query, data.len()).build()?;
-                iter_grid(&mut batch, data, pb, datatype_indices, use_pb)?;
+                    oracle::Connection::connect(username, password, connection_string)?;
+                let report = insert_batch(
+                    &conn,
+                    &query,
+                    data,
+                    thread_offset,
+                    &pb,
+                    &datatype_indices,
+                    use_pb,
+                    policy,
+                )?;
                 conn.commit()?;
-                Ok(())
+                Ok(report)
             }))
         }
 
+        let mut report = InsertReport::default();
         for handle in handles {
-            handle.join().unwrap()?;
+            report.merge(handle.join().map_err(|_| Error::InsertThreadPanicked)??);
         }
+        Ok(report)
+    }
+}
+
+// Splits the grid into batches of `chunk_size` rows, keyed by the offset of their first row,
+// and spreads the batches that haven't been committed yet across the threads.
+// Each batch is committed on its own, along with its checkpoint.
+#[allow(clippy::too_many_arguments)]
+fn oracle_insert_checkpointed(
+    conn_info: &OracleConnect,
+    query: &str,
+    grid: Vec<Vec<SQLDataTypes>>,
+    chunk_size: usize,
+    checkpoint: (CheckpointLog, HashSet<usize>),
+    progress_bar: Arc<ProgressBar>,
+    datatype_indices: DatatypeIndices,
+    use_pb: bool,
+    policy: ErrorPolicy,
+) -> Result<InsertReport, Error> {
+    let (log, committed) = checkpoint;
+    let log = Arc::new(log);
+    let nthreads = num_cpus::get();
+    let chunk_size = chunk_size.max(1);
+
+    let mut thread_batches: Vec<Vec<(usize, Vec<Vec<SQLDataTypes>>)>> = vec![Vec::new(); nthreads];
+    let mut rows = grid.into_iter();
+    let mut offset = 0;
+    let mut pending = 0;
+    loop {
+        let batch = rows
+            .by_ref()
+            .take(chunk_size)
+            .collect::<Vec<Vec<SQLDataTypes>>>();
+        if batch.is_empty() {
+            break;
+        }
+        let len = batch.len();
+        if committed.contains(&offset) {
+            if use_pb {
+                progress_bar.inc(len as u64);
+            }
+        } else {
+            thread_batches[pending % nthreads].push((offset, batch));
+            pending += 1;
+        }
+        offset += len;
+    }
+
+    let mut handles: Vec<JoinHandle<Result<InsertReport, Error>>> = Vec::new();
+    for batches in thread_batches
+        .into_iter()
+        .filter(|batches| !batches.is_empty())
+    {
+        let conn_info = conn_info.to_owned();
+        let query = query.to_string();
+        let log = Arc::clone(&log);
+        let pb = Arc::clone(&progress_bar);
+        let datatype_indices = datatype_indices.clone();
+        handles.push(thread::spawn(move || {
+            let conn: oracle::Connection = oracle::Connection::connect(
+                &conn_info.username,
+                &conn_info.password,
+                &conn_info.connection_string,
+            )?;
+            let mut report = InsertReport::default();
+            for (offset, batch) in batches {
+                report.merge(insert_batch(
+                    &conn,
+                    &query,
+                    batch,
+                    offset,
+                    &pb,
+                    &datatype_indices,
+                    use_pb,
+                    policy,
+                )?);
+                log.record_oracle(&conn, offset)?;
+                conn.commit()?;
+                log.committed(offset)?;
+            }
+            Ok(report)
+        }))
     }
 
+    let mut report = InsertReport::default();
+    for handle in handles {
+        report.merge(handle.join().map_err(|_| Error::InsertThreadPanicked)??);
+    }
+    Ok(report)
+}
+
+// Executes one thread's rows without committing them. Batch errors mode runs every row and lists the ones that failed,
+// without it Oracle stops at the first failed row.
+#[allow(clippy::too_many_arguments)]
+fn insert_batch(
+    conn: &oracle::Connection,
+    query: &str,
+    data: Vec<Vec<SQLDataTypes>>,
+    offset: usize,
+    progress_bar: &ProgressBar,
+    datatype_indices: &DatatypeIndices,
+    use_pb: bool,
+    policy: ErrorPolicy,
+) -> Result<InsertReport, Error> {
+    let mut builder = conn.batch(query, data.len());
+    if policy != ErrorPolicy::Abort {
+        builder.with_batch_errors();
+    }
+    // A MERGE that skips a matched row affects nothing, so the rows are counted from what each one affected
+    builder.with_row_counts();
+    let mut batch = builder.build()?;
+    bind_grid(&mut batch, &data, progress_bar, datatype_indices, use_pb)?;
+
+    let mut report = InsertReport::default();
+    match batch.execute() {
+        Ok(()) => report.inserted = affected_rows(&batch)?,
+        Err(err) => match (err.batch_errors(), err.db_error()) {
+            (Some(batch_errors), _) => {
+                let mut batch_errors = batch_errors.to_owned();
+                batch_errors.sort_by_key(|db_error| db_error.offset());
+                report.inserted = affected_rows(&batch)?;
+                for db_error in batch_errors {
+                    let idx = db_error.offset() as usize;
+                    report.row_failed(
+                        policy,
+                        offset + idx,
+                        &data[idx],
+                        db_error.message().to_string(),
+                    )?;
+                }
+            }
+            (None, Some(db_error)) if policy == ErrorPolicy::Abort => {
+                let idx = db_error.offset() as usize;
+                return Err(Error::RowInsertError(
+                    offset + idx,
+                    db_error.message().to_string(),
+                ));
+            }
+            _ => return Err(err.into()),
+        },
+    }
+    Ok(report)
+}
+
+fn affected_rows(batch: &oracle::Batch<'_>) -> Result<usize, Error> {
+    Ok(batch.row_counts()?.iter().sum::<u64>() as usize)
+}
+
+pub(crate) fn oracle_build_insert_from(insert_from: InsertFromProps) -> Result<(), Error> {
+    let conn_info = match (&insert_from.connect, &insert_from.select.connect) {
+        (SQLImplementation::Oracle(oracle_connect), SQLImplementation::Oracle(_)) => oracle_connect,
+        _ => return Err(Error::SQLVariationError),
+    };
+
+    let select = insert_from.select.to_sql()?;
+    if !does_table_exist(&insert_from.table, conn_info)? {
+        match insert_from.create {
+            true => insert_from.create_target(&select)?,
+            false => return Err(Error::TableDoesNotExist),
+        }
+    }
+
+    let conn: oracle::Connection = oracle::Connection::connect(
+        &conn_info.username,
+        &conn_info.password,
+        &conn_info.connection_string,
+    )?;
+    conn.execute(&insert_from.to_sql(&select), &[])?;
+    conn.commit()?;
     Ok(())
 }
+
+pub(crate) fn oracle_build_insert_returning(
+    insert_returning: InsertReturning,
+) -> Result<Vec<Vec<SQLDataTypes>>, Error> {
+    let insert_props = insert_returning.insert_props;
+    let conn_info = match &insert_props.connect {
+        SQLImplementation::Oracle(oracle_connect) => oracle_connect,
+        SQLImplementation::SQLite(_) => return Err(Error::SQLVariationError),
+    };
+    if insert_props.on_conflict.is_some() {
+        return Err(Error::MergeReturning);
+    }
+
+    let table_exist = does_table_exist(&insert_props.table, conn_info)?;
+    if !table_exist && insert_props.create {
+        let col_type_indexes = get_col_indexes(&insert_props.grid)?;
+        let columns = col_type_indexes.to_create_columns(&insert_props.header);
+        conn_info
+            .create()
+            .table(&insert_props.table, columns)
+            .build()?;
+    } else if !table_exist && !insert_props.create {
+        return Err(Error::TableDoesNotExist);
+    }
+
+    let len = insert_props.header.len();
+    let query = format!(
+        "{}{}",
+        insert_stmt(len, &insert_props.table, &insert_props.header.join(", ")),
+        fmt_returning_into(&insert_returning.columns, len)
+    );
+    let binds = returning_binds(conn_info, &insert_props.table, &insert_returning.columns)?;
+
+    let conn: oracle::Connection = oracle::Connection::connect(
+        &conn_info.username,
+        &conn_info.password,
+        &conn_info.connection_string,
+    )?;
+    let mut stmt = conn.statement(&query).build()?;
+    let mut res = Vec::new();
+    for row in &insert_props.grid {
+        let params = row
+            .iter()
+            .map(|cell| cell as &dyn oracle::sql_type::ToSql)
+            .chain(
+                binds
+                    .iter()
+                    .map(|bind| bind as &dyn oracle::sql_type::ToSql),
+            )
+            .collect::<Vec<&dyn oracle::sql_type::ToSql>>();
+        stmt.execute(&params)?;
+        res.extend(returned_rows(&stmt, len, insert_returning.columns.len())?);
+    }
+    conn.commit()?;
+    Ok(res)
+}
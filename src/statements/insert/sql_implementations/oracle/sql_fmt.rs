@@ -25,3 +25,43 @@ pub(crate) fn insert_stmt(length: usize, table: &String, header: &String) -> Str
         values.join(", ")
     )
 }
+
+// Upserts one row per batch row, the bind positions line up with `insert_stmt` so `iter_grid` can bind either
+pub(crate) fn merge_stmt(
+    table: &str,
+    header: &[String],
+    keys: &[String],
+    update: &[String],
+) -> String {
+    let source = header
+        .iter()
+        .enumerate()
+        .map(|(idx, col)| format!(":{} AS {}", idx + 1, col))
+        .collect::<Vec<String>>();
+    let on = keys
+        .iter()
+        .map(|key| format!("t.{key} = s.{key}"))
+        .collect::<Vec<String>>();
+    let mut query = format!(
+        "MERGE INTO {} t USING (SELECT {} FROM dual) s ON ({})",
+        table,
+        source.join(", "),
+        on.join(" AND ")
+    );
+    if !update.is_empty() {
+        let set = update
+            .iter()
+            .map(|col| format!("t.{col} = s.{col}"))
+            .collect::<Vec<String>>();
+        query = format!("{query} WHEN MATCHED THEN UPDATE SET {}", set.join(", "));
+    }
+    let values = header
+        .iter()
+        .map(|col| format!("s.{col}"))
+        .collect::<Vec<String>>();
+    format!(
+        "{query} WHEN NOT MATCHED THEN INSERT ({}) VALUES ({})",
+        header.join(", "),
+        values.join(", ")
+    )
+}
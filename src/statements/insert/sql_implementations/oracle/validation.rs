@@ -1,8 +1,13 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, str::FromStr};
 
-use itertools::Itertools;
+use bigdecimal::BigDecimal;
 
-use crate::{Error, data_types::SQLDataTypes, sql_implementations::OracleConnect};
+use crate::{
+    Error,
+    data_types::{SQLDataTypes, SQLInterval},
+    sql_implementations::{OracleConnect, utils::keep_dominant},
+    statements::create::{CreateColumns, CreateDataTypes},
+};
 
 pub(crate) fn does_table_exist(table: &String, conn_info: &OracleConnect) -> Result<bool, Error> {
     let conn: oracle::Connection = oracle::Connection::connect(
@@ -27,10 +32,19 @@ pub(crate) fn does_table_exist(table: &String, conn_info: &OracleConnect) -> Res
 #[derive(Debug)]
 pub struct DatatypeIndexes {
     pub is_varchar: Vec<usize>,
+    pub is_json: Vec<usize>,
+    pub is_decimal: Vec<usize>,
     pub is_float: Vec<usize>,
     pub is_int: Vec<usize>,
+    pub is_bool: Vec<usize>,
+    pub is_timestamptz: Vec<usize>,
+    pub is_timestamp: Vec<usize>,
     pub is_date: Vec<usize>,
+    pub is_time: Vec<usize>,
+    pub is_interval: Vec<usize>,
+    pub is_interval_ym: Vec<usize>,
     pub varchar_size: HashMap<usize, usize>,
+    pub decimal_size: HashMap<usize, (usize, usize)>,
 }
 
 #[derive(Debug)]
@@ -41,12 +55,21 @@ pub struct VarcharProps {
 
 pub(crate) fn get_col_indexes(grid: &Vec<Vec<SQLDataTypes>>) -> Result<DatatypeIndexes, Error> {
     // get's the 'dominate' datatype from each column
-    // weighted in order: VARCHAR2, FLOAT, INT, DATE
+    // weighted in order: VARCHAR2, JSON, DECIMAL, FLOAT, INT, BOOL, TIMESTAMPTZ, TIMESTAMP, DATE, TIME, INTERVAL, INTERVAL YEAR TO MONTH
     let mut is_varchar: Vec<usize> = Vec::new();
+    let mut is_json: Vec<usize> = Vec::new();
+    let mut is_decimal: Vec<usize> = Vec::new();
     let mut is_float: Vec<usize> = Vec::new();
     let mut is_int: Vec<usize> = Vec::new();
+    let mut is_bool: Vec<usize> = Vec::new();
+    let mut is_timestamptz: Vec<usize> = Vec::new();
+    let mut is_timestamp: Vec<usize> = Vec::new();
     let mut is_date: Vec<usize> = Vec::new();
+    let mut is_time: Vec<usize> = Vec::new();
+    let mut is_interval: Vec<usize> = Vec::new();
+    let mut is_interval_ym: Vec<usize> = Vec::new();
     let varchar_size = HashMap::new();
+    let decimal_size = HashMap::new();
 
     for row in grid.iter() {
         for (x_idx, cell) in row.iter().enumerate() {
@@ -54,7 +77,17 @@ pub(crate) fn get_col_indexes(grid: &Vec<Vec<SQLDataTypes>>) -> Result<DatatypeI
                 SQLDataTypes::Varchar(_) => is_varchar.push(x_idx),
                 SQLDataTypes::Number(_) => is_int.push(x_idx),
                 SQLDataTypes::Float(_) => is_float.push(x_idx),
+                SQLDataTypes::Decimal(_) => is_decimal.push(x_idx),
                 SQLDataTypes::Date(_) => is_date.push(x_idx),
+                SQLDataTypes::Time(_) => is_time.push(x_idx),
+                SQLDataTypes::Timestamp(_) => is_timestamp.push(x_idx),
+                SQLDataTypes::TimestampTz(_) => is_timestamptz.push(x_idx),
+                SQLDataTypes::Interval(SQLInterval::DayToSecond(_)) => is_interval.push(x_idx),
+                SQLDataTypes::Interval(SQLInterval::YearToMonth { .. }) => {
+                    is_interval_ym.push(x_idx)
+                }
+                SQLDataTypes::Bool(_) => is_bool.push(x_idx),
+                SQLDataTypes::Json(_) => is_json.push(x_idx),
                 SQLDataTypes::NULL => continue,
             }
         }
@@ -62,54 +95,92 @@ pub(crate) fn get_col_indexes(grid: &Vec<Vec<SQLDataTypes>>) -> Result<DatatypeI
 
     let data_type_indexes = DatatypeIndexes {
         is_varchar,
+        is_json,
+        is_decimal,
         is_float,
         is_int,
+        is_bool,
+        is_timestamptz,
+        is_timestamp,
         is_date,
+        is_time,
+        is_interval,
+        is_interval_ym,
         varchar_size,
+        decimal_size,
     };
 
-    Ok(data_type_indexes.find_uniques().get_varchar_sizes(grid))
+    Ok(data_type_indexes
+        .find_uniques()
+        .get_varchar_sizes(grid)
+        .get_decimal_sizes(grid))
+}
+
+/// The integer digits and scale of a numeric cell, which size a decimal column's `NUMBER(precision, scale)`.
+pub(crate) fn decimal_digits(cell: &SQLDataTypes) -> Option<(usize, usize)> {
+    let val = match cell {
+        SQLDataTypes::Decimal(val) => val.to_owned(),
+        SQLDataTypes::Number(val) => BigDecimal::from(*val),
+        SQLDataTypes::Float(val) => BigDecimal::from_str(&val.to_string()).ok()?,
+        _ => return None,
+    };
+    let (_, scale) = val.as_bigint_and_exponent();
+    let scale = scale.max(0) as usize;
+    let int_digits = (val.digits() as usize).saturating_sub(scale);
+    Some((int_digits, scale))
 }
 
 impl DatatypeIndexes {
-    pub(crate) fn find_uniques(mut self) -> Self {
-        let is_varchar = self.is_varchar.into_iter().unique().collect::<Vec<usize>>();
-        for x_index in is_varchar.iter() {
-            if self.is_float.contains(x_index) {
-                self.is_float.retain(|v| *v != *x_index);
-            } else if self.is_int.contains(x_index) {
-                self.is_int.retain(|v| *v != *x_index);
-            } else if self.is_date.contains(x_index) {
-                self.is_date.retain(|v| *v != *x_index);
-            } else {
-                continue;
-            }
-        }
-        let is_float = self.is_float.into_iter().unique().collect::<Vec<usize>>();
-        for x_index in is_float.iter() {
-            if self.is_int.contains(x_index) {
-                self.is_int.retain(|v| *v != *x_index);
-            } else if self.is_date.contains(x_index) {
-                self.is_date.retain(|v| *v != *x_index);
-            } else {
-                continue;
-            }
-        }
-        let is_int = self.is_int.into_iter().unique().collect::<Vec<usize>>();
-        for x_index in is_int.iter() {
-            if self.is_date.contains(x_index) {
-                self.is_date.retain(|v| *v != *x_index);
-            } else {
-                continue;
-            }
-        }
-        let is_date = self.is_date.into_iter().unique().collect::<Vec<usize>>();
+    pub(crate) fn find_uniques(self) -> Self {
+        // A column with both kinds of interval doesn't fit either one
+        let mixed_intervals = self
+            .is_interval
+            .iter()
+            .filter(|idx| self.is_interval_ym.contains(idx))
+            .copied()
+            .collect::<Vec<usize>>();
+        let [
+            is_varchar,
+            is_json,
+            is_decimal,
+            is_float,
+            is_int,
+            is_bool,
+            is_timestamptz,
+            is_timestamp,
+            is_date,
+            is_time,
+            is_interval,
+            is_interval_ym,
+        ] = keep_dominant([
+            [self.is_varchar, mixed_intervals].concat(),
+            self.is_json,
+            self.is_decimal,
+            self.is_float,
+            self.is_int,
+            self.is_bool,
+            self.is_timestamptz,
+            self.is_timestamp,
+            self.is_date,
+            self.is_time,
+            self.is_interval,
+            self.is_interval_ym,
+        ]);
         Self {
             is_varchar,
+            is_json,
+            is_decimal,
             is_float,
             is_int,
+            is_bool,
+            is_timestamptz,
+            is_timestamp,
             is_date,
+            is_time,
+            is_interval,
+            is_interval_ym,
             varchar_size: self.varchar_size,
+            decimal_size: self.decimal_size,
         }
     }
 
@@ -122,8 +193,9 @@ impl DatatypeIndexes {
                         SQLDataTypes::Varchar(val) => val.to_owned(),
                         SQLDataTypes::Number(val) => format!("{}", val),
                         SQLDataTypes::Float(val) => format!("{}", val),
-                        SQLDataTypes::Date(val) => format!("{}", val),
+                        SQLDataTypes::Decimal(val) => val.to_plain_string(),
                         SQLDataTypes::NULL => format!(""),
+                        other => other.to_string(),
                     };
                     if let Some(existing_size) = varchar_size.get(&x_idx) {
                         if val.len() > *existing_size {
@@ -142,4 +214,80 @@ impl DatatypeIndexes {
         self.varchar_size = varchar_size;
         self
     }
+
+    pub(crate) fn get_decimal_sizes(mut self, grid: &[Vec<SQLDataTypes>]) -> Self {
+        // (integer digits, scale) for each decimal column, turned into NUMBER(precision, scale)
+        let mut digits: HashMap<usize, (usize, usize)> = HashMap::new();
+        for row in grid.iter() {
+            for (x_idx, cell) in row.iter().enumerate() {
+                if !self.is_decimal.contains(&x_idx) {
+                    continue;
+                }
+                let (int_digits, scale) = match decimal_digits(cell) {
+                    Some(val) => val,
+                    None => continue,
+                };
+                let existing = digits.entry(x_idx).or_insert((0, 0));
+                existing.0 = existing.0.max(int_digits);
+                existing.1 = existing.1.max(scale);
+            }
+        }
+
+        self.decimal_size = digits
+            .into_iter()
+            .map(|(x_idx, (int_digits, scale))| {
+                // Oracle caps NUMBER precision at 38, so past it the scale gives way to the integer digits
+                let precision = (int_digits + scale).clamp(1, 38);
+                let scale = scale.min(precision.saturating_sub(int_digits));
+                (x_idx, (precision, scale))
+            })
+            .collect();
+        self
+    }
+
+    /// Pairs each header with the `CreateDataTypes` that fits the data in its column.
+    pub(crate) fn to_create_columns(&self, header: &[String]) -> Vec<CreateColumns> {
+        header
+            .iter()
+            .enumerate()
+            .map(|(idx, cell)| {
+                let data_type = if self.is_date.contains(&idx) {
+                    CreateDataTypes::DATE
+                } else if self.is_time.contains(&idx) {
+                    CreateDataTypes::TIME
+                } else if self.is_timestamp.contains(&idx) {
+                    CreateDataTypes::TIMESTAMP
+                } else if self.is_timestamptz.contains(&idx) {
+                    CreateDataTypes::TIMESTAMPTZ
+                } else if self.is_interval.contains(&idx) {
+                    CreateDataTypes::INTERVAL
+                } else if self.is_interval_ym.contains(&idx) {
+                    CreateDataTypes::INTERVALYM
+                } else if self.is_bool.contains(&idx) {
+                    CreateDataTypes::BOOL
+                } else if self.is_json.contains(&idx) {
+                    CreateDataTypes::JSON
+                } else if self.is_int.contains(&idx) {
+                    CreateDataTypes::NUMBER(0, 0)
+                } else if self.is_decimal.contains(&idx) {
+                    let (precision, scale) = if let Some(val) = self.decimal_size.get(&idx) {
+                        *val
+                    } else {
+                        (38, 0)
+                    };
+                    CreateDataTypes::NUMBER(precision, scale)
+                } else if self.is_float.contains(&idx) {
+                    CreateDataTypes::FLOAT
+                } else {
+                    let size = if let Some(val) = self.varchar_size.get(&idx) {
+                        *val
+                    } else {
+                        1
+                    };
+                    CreateDataTypes::VARCHAR(size)
+                };
+                CreateColumns::new(cell, data_type)
+            })
+            .collect::<Vec<CreateColumns>>()
+    }
 }
@@ -3,115 +3,169 @@ use indicatif::ProgressBar;
 use crate::{
     Error, SQLImplementation,
     data_types::SQLDataTypes,
+    sql_implementations::sqlite::returning_rows,
     statements::insert::{
-        InsertProps,
-        sql_implementations::sqlite::utils::{create_sqlite_table, does_sqlite_table_exist},
+        ErrorPolicy, InsertFromProps, InsertProps, InsertReport, InsertReturning,
+        checkpoint::CheckpointLog,
+        sql_implementations::sqlite::utils::{
+            create_sqlite_table, does_sqlite_table_exist, fmt_on_conflict,
+        },
     },
 };
 
 pub(crate) mod utils;
 
-pub(crate) fn sqlite_build_insert(insert_props: InsertProps) -> Result<(), Error> {
+pub(crate) fn sqlite_build_insert(
+    insert_props: InsertProps,
+    use_pb: bool,
+    policy: ErrorPolicy,
+) -> Result<InsertReport, Error> {
     let conn_info = match &insert_props.connect {
         SQLImplementation::Oracle(_) => return Err(Error::SQLVariationError),
         SQLImplementation::SQLite(connect) => connect,
     };
 
-    let table_exist = does_sqlite_table_exist(&insert_props, &conn_info)?;
+    let table_exist = does_sqlite_table_exist(&insert_props, conn_info)?;
     if !table_exist && insert_props.create {
         create_sqlite_table(&insert_props, conn_info)?;
     } else if !table_exist && !insert_props.create {
         return Err(Error::TableDoesNotExist);
     }
 
-    let mut values = Vec::new();
-    for idx in 0..insert_props.grid.len() {
-        values.push(["?", &(idx + 1).to_string()].concat())
+    let on_conflict = fmt_on_conflict(&insert_props)?;
+    let values = (1..=insert_props.header.len())
+        .map(|idx| format!("?{idx}"))
+        .collect::<Vec<String>>();
+    let query = format!(
+        "INSERT INTO {} ({}) VALUES ({}){}",
+        insert_props.table,
+        insert_props.header.join(", "),
+        values.join(", "),
+        on_conflict
+    );
+
+    let progress_bar = match use_pb {
+        true => ProgressBar::new(insert_props.grid.len() as u64),
+        false => ProgressBar::hidden(),
+    };
+
+    let checkpoint = CheckpointLog::open(&insert_props)?;
+
+    // Each chunk is its own transaction, reusing the same prepared statement
+    let mut report = InsertReport::default();
+    let mut conn = conn_info.initialize_connection()?;
+    let chunk_size = insert_props.chunk_size.max(1);
+    for (chunk_idx, chunk) in insert_props.grid.chunks(chunk_size).enumerate() {
+        let offset = chunk_idx * chunk_size;
+        if let Some((_, committed)) = &checkpoint
+            && committed.contains(&offset)
+        {
+            progress_bar.inc(chunk.len() as u64);
+            continue;
+        }
+        let transaction = conn.transaction()?;
+        {
+            let mut stmt = transaction.prepare_cached(&query)?;
+            for (row_idx, row) in chunk.iter().enumerate() {
+                // A failed row only rolls back its own statement, so the transaction carries on
+                // Rows an ON CONFLICT DO NOTHING skips don't change anything, so they aren't counted
+                match stmt.execute(rusqlite::params_from_iter(row.iter())) {
+                    Ok(changed) => report.inserted += changed,
+                    Err(err) if is_row_error(&err) => {
+                        report.row_failed(policy, offset + row_idx, row, err.to_string())?;
+                    }
+                    Err(err) => return Err(err.into()),
+                }
+            }
+        }
+        if let Some((log, _)) = &checkpoint {
+            log.record_sqlite(&transaction, offset)?;
+        }
+        transaction.commit()?;
+        if let Some((log, _)) = &checkpoint {
+            log.committed(offset)?;
+        }
+        progress_bar.inc(chunk.len() as u64);
     }
+    progress_bar.finish();
 
-    let conn = conn_info.initialize_connection()?;
+    Ok(report)
+}
 
-    let mut batch: Vec<String> = Vec::new();
-    insert_props.grid.iter().for_each(|row| {
-        let values = row
-            .iter()
-            .map(|cell| match cell {
-                SQLDataTypes::Varchar(val) => format!("'{val}'"),
-                SQLDataTypes::Number(val) => format!("{val}"),
-                SQLDataTypes::Float(val) => format!("{val}"),
-                SQLDataTypes::Date(val) => format!("'{}'", val.to_string()),
-                SQLDataTypes::NULL => format!("NULL"),
-            })
-            .collect::<Vec<String>>();
-        let sql = format!(
-            "INSERT INTO {} ({}) VALUES ({})",
-            insert_props.table,
-            insert_props.header.join(", "),
-            values.join(", ")
-        );
-        batch.push(sql);
-    });
-
-    let sql = format!(
-        "
-        BEGIN;
-        {};
-        COMMIT;
-    ",
-        batch.join("; ")
-    );
-    conn.execute_batch(&sql)?;
+// Errors caused by a row's values. Anything else, like a locked or full database, fails every row after it too
+fn is_row_error(err: &rusqlite::Error) -> bool {
+    match err {
+        rusqlite::Error::SqliteFailure(err, _) => matches!(
+            err.code,
+            rusqlite::ErrorCode::ConstraintViolation
+                | rusqlite::ErrorCode::TypeMismatch
+                | rusqlite::ErrorCode::TooBig
+        ),
+        rusqlite::Error::ToSqlConversionFailure(_) => true,
+        _ => false,
+    }
+}
+
+pub(crate) fn sqlite_build_insert_from(insert_from: InsertFromProps) -> Result<(), Error> {
+    let conn_info = match (&insert_from.connect, &insert_from.select.connect) {
+        (SQLImplementation::SQLite(connect), SQLImplementation::SQLite(_)) => connect,
+        _ => return Err(Error::SQLVariationError),
+    };
 
+    let select = insert_from.select.to_sql()?;
+    if conn_info.table_info(&insert_from.table).is_err() {
+        match insert_from.create {
+            true => insert_from.create_target(&select)?,
+            false => return Err(Error::TableDoesNotExist),
+        }
+    }
+
+    let conn = conn_info.initialize_connection()?;
+    conn.execute(&insert_from.to_sql(&select), ())?;
     Ok(())
 }
 
-pub(crate) fn sqlite_build_insert_pb(insert_props: InsertProps) -> Result<(), Error> {
+pub(crate) fn sqlite_build_insert_returning(
+    insert_returning: InsertReturning,
+) -> Result<Vec<Vec<SQLDataTypes>>, Error> {
+    let insert_props = insert_returning.insert_props;
     let conn_info = match &insert_props.connect {
         SQLImplementation::Oracle(_) => return Err(Error::SQLVariationError),
         SQLImplementation::SQLite(connect) => connect,
     };
 
-    does_sqlite_table_exist(&insert_props, conn_info)?;
-
-    let mut values = Vec::new();
-    for idx in 0..insert_props.grid.len() {
-        values.push(["?", &(idx + 1).to_string()].concat())
+    let table_exist = does_sqlite_table_exist(&insert_props, conn_info)?;
+    if !table_exist && insert_props.create {
+        create_sqlite_table(&insert_props, conn_info)?;
+    } else if !table_exist && !insert_props.create {
+        return Err(Error::TableDoesNotExist);
     }
 
-    let conn = conn_info.initialize_connection()?;
-    let progress_bar = ProgressBar::new(*&insert_props.grid.len() as u64);
-
-    let mut batch: Vec<String> = Vec::new();
-    insert_props.grid.iter().for_each(|row| {
-        let values = row
-            .iter()
-            .map(|cell| match cell {
-                SQLDataTypes::Varchar(val) => format!("'{val}'"),
-                SQLDataTypes::Number(val) => format!("{val}"),
-                SQLDataTypes::Float(val) => format!("{val}"),
-                SQLDataTypes::Date(val) => format!("'{}'", val.to_string()),
-                SQLDataTypes::NULL => format!(""),
-            })
-            .collect::<Vec<String>>();
-        let sql = format!(
-            "INSERT INTO {} ({}) VALUES ({})",
-            insert_props.table,
-            insert_props.header.join(", "),
-            values.join(", ")
-        );
-        batch.push(sql);
-        progress_bar.inc(1u64);
-    });
-
-    let sql = format!(
-        "
-        BEGIN;
-        {};
-        COMMIT;
-    ",
-        batch.join("; ")
+    let on_conflict = fmt_on_conflict(&insert_props)?;
+    let values = (1..=insert_props.header.len())
+        .map(|idx| format!("?{idx}"))
+        .collect::<Vec<String>>();
+    let query = format!(
+        "INSERT INTO {} ({}) VALUES ({}){} RETURNING {}",
+        insert_props.table,
+        insert_props.header.join(", "),
+        values.join(", "),
+        on_conflict,
+        insert_returning.columns.join(", ")
     );
-    conn.execute_batch(&sql)?;
 
-    Ok(())
+    let mut conn = conn_info.initialize_connection()?;
+    let transaction = conn.transaction()?;
+    let mut res = Vec::new();
+    {
+        let mut stmt = transaction.prepare(&query)?;
+        for row in &insert_props.grid {
+            res.extend(returning_rows(
+                &mut stmt,
+                rusqlite::params_from_iter(row.iter()),
+            )?);
+        }
+    }
+    transaction.commit()?;
+    Ok(res)
 }
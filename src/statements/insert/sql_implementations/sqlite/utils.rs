@@ -2,7 +2,7 @@ use crate::{
     Error, QueryBuilder,
     sql_implementations::SQLiteConnect,
     statements::{
-        create::{CreateColumns, CreateDataTypes, ModifyCreateTable},
+        create::ModifyCreateTable,
         insert::{InsertProps, sql_implementations::oracle::validation::get_col_indexes},
     },
 };
@@ -23,42 +23,34 @@ pub(crate) fn create_sqlite_table(
     conn_info: &SQLiteConnect,
 ) -> Result<(), Error> {
     let col_type_indexes = get_col_indexes(&insert_props.grid)?;
-    let columns = &insert_props
-        .header
-        .iter()
-        .enumerate()
-        .map(|(idx, cell)| {
-            if col_type_indexes.is_date.contains(&idx) {
-                CreateColumns {
-                    name: cell.to_string(),
-                    data_type: CreateDataTypes::DATE,
-                }
-            } else if col_type_indexes.is_int.contains(&idx) {
-                CreateColumns {
-                    name: cell.to_string(),
-                    data_type: CreateDataTypes::NUMBER,
-                }
-            } else if col_type_indexes.is_float.contains(&idx) {
-                CreateColumns {
-                    name: cell.to_string(),
-                    data_type: CreateDataTypes::FLOAT,
-                }
-            } else {
-                let size = if let Some(val) = col_type_indexes.varchar_size.get(&idx) {
-                    val
-                } else {
-                    &(1 as usize)
-                };
-                CreateColumns {
-                    name: cell.to_string(),
-                    data_type: CreateDataTypes::VARCHAR(*size),
-                }
-            }
-        })
-        .collect::<Vec<CreateColumns>>();
-    conn_info
-        .create()
-        .table(&insert_props.table, columns.to_vec())
-        .build()?;
+    let columns = col_type_indexes.to_create_columns(&insert_props.header);
+    let mut create_table = conn_info.create().table(&insert_props.table, columns);
+    // ON CONFLICT needs a unique constraint on the key columns
+    if let Some(on_conflict) = &insert_props.on_conflict {
+        let (keys, _) = on_conflict.resolve(&insert_props.header)?;
+        let keys = keys.iter().map(|key| key.as_str()).collect::<Vec<&str>>();
+        create_table = create_table.unique(&keys);
+    }
+    create_table.build()?;
     Ok(())
 }
+
+pub(crate) fn fmt_on_conflict(insert_props: &InsertProps) -> Result<String, Error> {
+    let on_conflict = match &insert_props.on_conflict {
+        Some(on_conflict) => on_conflict,
+        None => return Ok(String::new()),
+    };
+    let (keys, update) = on_conflict.resolve(&insert_props.header)?;
+    if update.is_empty() {
+        return Ok(format!(" ON CONFLICT ({}) DO NOTHING", keys.join(", ")));
+    }
+    let set = update
+        .iter()
+        .map(|col| format!("{col} = excluded.{col}"))
+        .collect::<Vec<String>>();
+    Ok(format!(
+        " ON CONFLICT ({}) DO UPDATE SET {}",
+        keys.join(", "),
+        set.join(", ")
+    ))
+}
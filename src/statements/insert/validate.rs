@@ -0,0 +1,276 @@
+use std::str::FromStr;
+
+use bigdecimal::BigDecimal;
+
+use crate::{
+    Error, SQLImplementation,
+    data_types::{SQLDataTypes, SQLInterval},
+    schema::TableSchema,
+    statements::create::{CreateDataTypes, CreateTable},
+    utils::remove_invalid_chars,
+};
+
+use super::{InsertProps, ValidationIssue, ValidationReport};
+
+// A column the grid is checked against, from either the live schema or a CreateTable
+struct ExpectedColumn {
+    name: String,
+    data_type: CreateDataTypes,
+    nullable: bool,
+    has_default: bool,
+    /// Only set when the length was declared, since unsized text columns are read back as `VARCHAR(4000)`.
+    max_length: Option<usize>,
+}
+
+impl ValidationReport {
+    /// Whether the grid can be inserted without any of the issues found.
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl InsertProps {
+    /// Checks every cell of the grid against the live table's declared types, lengths and `NOT NULL` constraints,
+    /// without writing anything. Takes the [`map_column`](super::InsertBuilder::map_column) pairs into account.
+    /// ```no_run
+    /// let insert = conn.insert("regional_sales", data)?.format_grid_strings()?;
+    /// let report = insert.validate()?;
+    /// if report.is_valid() {
+    ///     insert.build()?;
+    /// } else {
+    ///     report.issues.iter().for_each(|issue| println!("{:?}", issue));
+    /// }
+    /// ```
+    pub fn validate(&self) -> Result<ValidationReport, Error> {
+        let schema = self.connect.describe_table(&self.table)?;
+        Ok(self.validate_columns(&expected_from_schema(&schema)))
+    }
+
+    /// Checks the grid against a [`CreateTable`] instead of the live table, e.g. before the table is created.
+    pub fn validate_against(&self, create_table: &CreateTable) -> ValidationReport {
+        self.validate_columns(&expected_from_create(create_table))
+    }
+
+    fn validate_columns(&self, expected: &[ExpectedColumn]) -> ValidationReport {
+        let mut report = ValidationReport::default();
+
+        // Pairs of (grid index, table column) after applying the column map
+        let targets = match self.column_map.is_empty() {
+            true => self
+                .header
+                .iter()
+                .enumerate()
+                .map(|(idx, head)| (Some(idx), head.to_string()))
+                .collect::<Vec<(Option<usize>, String)>>(),
+            false => self
+                .column_map
+                .iter()
+                .map(|(grid_column, table_column)| {
+                    let cleaned = remove_invalid_chars(grid_column);
+                    let idx = self.header.iter().position(|head| {
+                        head.eq_ignore_ascii_case(grid_column)
+                            || head.eq_ignore_ascii_case(&cleaned)
+                    });
+                    (idx, table_column.to_string())
+                })
+                .collect(),
+        };
+
+        let is_oracle = matches!(self.connect, SQLImplementation::Oracle(_));
+        let mut columns = Vec::new();
+        for (idx, name) in targets {
+            match expected
+                .iter()
+                .find(|column| column.name.eq_ignore_ascii_case(&name))
+            {
+                Some(column) => columns.push((idx, column)),
+                None => report
+                    .issues
+                    .push(ValidationIssue::UnknownColumn { column: name }),
+            }
+        }
+
+        expected
+            .iter()
+            .filter(|column| !column.nullable && !column.has_default)
+            .filter(|column| !columns.iter().any(|(_, found)| found.name == column.name))
+            .for_each(|column| {
+                report.issues.push(ValidationIssue::MissingColumn {
+                    column: column.name.to_string(),
+                })
+            });
+
+        for (row_idx, row) in self.grid.iter().enumerate() {
+            for (idx, column) in &columns {
+                let cell = idx
+                    .and_then(|idx| row.get(idx))
+                    .unwrap_or(&SQLDataTypes::NULL);
+                if let Some(issue) = check_cell(row_idx, cell, column, is_oracle) {
+                    report.issues.push(issue);
+                }
+            }
+        }
+        report
+    }
+}
+
+fn check_cell(
+    row: usize,
+    cell: &SQLDataTypes,
+    column: &ExpectedColumn,
+    is_oracle: bool,
+) -> Option<ValidationIssue> {
+    let is_null = match cell {
+        SQLDataTypes::NULL => true,
+        // Oracle stores an empty string as NULL, but keeps whitespace
+        SQLDataTypes::Varchar(val) => is_oracle && val.is_empty(),
+        _ => false,
+    };
+    if is_null {
+        return match column.nullable {
+            true => None,
+            false => Some(ValidationIssue::NullValue {
+                row,
+                column: column.name.to_string(),
+            }),
+        };
+    }
+
+    let type_conflict = || ValidationIssue::TypeConflict {
+        row,
+        column: column.name.to_string(),
+        expected: column.data_type.clone(),
+        value: cell.to_owned(),
+    };
+    let unparseable_date = |value: &str| ValidationIssue::UnparseableDate {
+        row,
+        column: column.name.to_string(),
+        value: value.to_string(),
+    };
+
+    match (&column.data_type, cell) {
+        (CreateDataTypes::VARCHAR(_) | CreateDataTypes::CLOB, _) => {
+            // VARCHAR2 lengths are in bytes
+            let length = cell.to_string().len();
+            match column.max_length {
+                Some(max) if length > max => Some(ValidationIssue::VarcharOverflow {
+                    row,
+                    column: column.name.to_string(),
+                    max,
+                    length,
+                }),
+                _ => None,
+            }
+        }
+        (
+            CreateDataTypes::NUMBER(..) | CreateDataTypes::FLOAT,
+            SQLDataTypes::Number(_)
+            | SQLDataTypes::Float(_)
+            | SQLDataTypes::Decimal(_)
+            | SQLDataTypes::Bool(_),
+        ) => None,
+        (CreateDataTypes::NUMBER(..) | CreateDataTypes::FLOAT, SQLDataTypes::Varchar(val)) => {
+            match BigDecimal::from_str(val.trim()) {
+                Ok(_) => None,
+                Err(_) => Some(type_conflict()),
+            }
+        }
+        (
+            CreateDataTypes::DATE | CreateDataTypes::TIMESTAMP | CreateDataTypes::TIMESTAMPTZ,
+            SQLDataTypes::Date(_) | SQLDataTypes::Timestamp(_) | SQLDataTypes::TimestampTz(_),
+        ) => None,
+        // Text is checked the same way format_grid_strings would convert it
+        (
+            CreateDataTypes::DATE | CreateDataTypes::TIMESTAMP | CreateDataTypes::TIMESTAMPTZ,
+            SQLDataTypes::Varchar(val),
+        ) => match cell.to_owned().format_data_types() {
+            SQLDataTypes::Date(_) | SQLDataTypes::Timestamp(_) | SQLDataTypes::TimestampTz(_) => {
+                None
+            }
+            _ => Some(unparseable_date(val)),
+        },
+        (CreateDataTypes::TIME, SQLDataTypes::Time(_)) => None,
+        (CreateDataTypes::TIME, SQLDataTypes::Varchar(val)) => {
+            match cell.to_owned().format_data_types() {
+                SQLDataTypes::Time(_) => None,
+                _ => Some(unparseable_date(val)),
+            }
+        }
+        (CreateDataTypes::INTERVAL, SQLDataTypes::Interval(SQLInterval::DayToSecond(_)))
+        | (CreateDataTypes::INTERVALYM, SQLDataTypes::Interval(SQLInterval::YearToMonth { .. })) => {
+            None
+        }
+        (CreateDataTypes::INTERVAL, SQLDataTypes::Varchar(val)) => match SQLInterval::parse(val) {
+            Some(SQLInterval::DayToSecond(_)) => None,
+            _ => Some(type_conflict()),
+        },
+        (CreateDataTypes::INTERVALYM, SQLDataTypes::Varchar(val)) => {
+            match SQLInterval::parse(val) {
+                Some(SQLInterval::YearToMonth { .. }) => None,
+                _ => Some(type_conflict()),
+            }
+        }
+        (CreateDataTypes::BOOL, SQLDataTypes::Bool(_)) => None,
+        (CreateDataTypes::BOOL, SQLDataTypes::Number(val)) if *val == 0 || *val == 1 => None,
+        (CreateDataTypes::BOOL, SQLDataTypes::Varchar(val)) => {
+            match val.trim().to_ascii_lowercase().as_str() {
+                "true" | "false" | "yes" | "no" | "1" | "0" => None,
+                _ => Some(type_conflict()),
+            }
+        }
+        (CreateDataTypes::JSON, SQLDataTypes::Varchar(val)) => {
+            match serde_json::from_str::<serde_json::Value>(val) {
+                Ok(_) => None,
+                Err(_) => Some(type_conflict()),
+            }
+        }
+        (
+            CreateDataTypes::JSON,
+            SQLDataTypes::Json(_)
+            | SQLDataTypes::Number(_)
+            | SQLDataTypes::Float(_)
+            | SQLDataTypes::Decimal(_)
+            | SQLDataTypes::Bool(_),
+        ) => None,
+        _ => Some(type_conflict()),
+    }
+}
+
+fn expected_from_schema(schema: &TableSchema) -> Vec<ExpectedColumn> {
+    schema
+        .columns
+        .iter()
+        .map(|column| ExpectedColumn {
+            name: column.name.to_string(),
+            data_type: column.data_type.clone(),
+            nullable: column.nullable,
+            has_default: column.default.is_some(),
+            max_length: match column.data_type {
+                CreateDataTypes::VARCHAR(size) if column.declared_type.contains('(') => Some(size),
+                _ => None,
+            },
+        })
+        .collect()
+}
+
+fn expected_from_create(create_table: &CreateTable) -> Vec<ExpectedColumn> {
+    create_table
+        .columns
+        .iter()
+        .map(|column| ExpectedColumn {
+            name: column.name.to_string(),
+            data_type: column.data_type.clone(),
+            nullable: !column.not_null
+                && !column.primary_key
+                && !create_table
+                    .primary_key
+                    .iter()
+                    .any(|pk| pk.eq_ignore_ascii_case(&column.name)),
+            has_default: column.default.is_some(),
+            max_length: match column.data_type {
+                CreateDataTypes::VARCHAR(size) => Some(size),
+                _ => None,
+            },
+        })
+        .collect()
+}
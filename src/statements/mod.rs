@@ -1,6 +1,8 @@
 pub mod alter;
 pub mod create;
 pub mod delete;
+pub mod drop;
 pub mod insert;
 pub mod select;
+pub mod truncate;
 pub mod update;
@@ -1,6 +1,7 @@
 use crate::{
     Error, SQLImplementation,
     data_types::SQLDataTypes,
+    export::{self, ExportOptions, JsonLayout},
     statements::select::{
         Column, JoinType, Joins, sql_implementations::{
             oracle::{oracle_build_select, oracle_build_single_thread_select},
@@ -116,4 +117,17 @@ impl SelectBuilder for SelectProps {
         self.return_header = true;
         self
     }
+
+    fn export_csv(self, path: &str, options: ExportOptions) -> Result<usize, Error> {
+        export::export_csv(self, path, options)
+    }
+
+    fn export_json(
+        self,
+        path: &str,
+        layout: JsonLayout,
+        options: ExportOptions,
+    ) -> Result<usize, Error> {
+        export::export_json(self, path, layout, options)
+    }
 }
@@ -1,4 +1,8 @@
-use crate::{Error, SQLImplementation, data_types::SQLDataTypes};
+use crate::{
+    Error, SQLImplementation,
+    data_types::SQLDataTypes,
+    export::{ExportOptions, JsonLayout},
+};
 
 pub mod implement;
 pub mod sql_implementations;
@@ -180,6 +184,37 @@ pub trait SelectBuilder {
 
     /// Builds the query only using one thread.
     fn build_single_thread(self) -> Result<Vec<Vec<Box<SQLDataTypes>>>, Error>;
+
+    /// Streams the rows into a CSV file instead of collecting them, returning the number of rows written.
+    /// ```no_run
+    /// let conn = SQLiteConnect::from_path("path/to/file.db");
+    /// let options = ExportOptions {
+    ///     delimiter: b';',
+    ///     date_format: "%d/%m/%Y".to_string(),
+    ///     null: NullValue::Literal("NULL".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// let city = ColumnProps { name: "city".to_string(), table: "regional_sales".to_string() };
+    /// let cities = WhereArg::Values(vec!["Austin".to_sql_fmt(), "Dallas".to_sql_fmt()]);
+    /// let rows = conn.select("regional_sales", vec![Column::ALL("regional_sales".to_string())])
+    ///     .where_in(&city, cities)
+    ///     .export_csv("texas_sales.csv", options)?;
+    /// ```
+    fn export_csv(self, path: &str, options: ExportOptions) -> Result<usize, Error>;
+
+    /// Streams the rows into a JSON file in the given [`JsonLayout`], returning the number of rows written.
+    /// Dates and timestamps are written as strings using the [`ExportOptions`] patterns.
+    /// ```no_run
+    /// let conn = SQLiteConnect::from_path("path/to/file.db");
+    /// let rows = conn.select("regional_sales", vec![Column::ALL("regional_sales".to_string())])
+    ///     .export_json("sales.ndjson", JsonLayout::Ndjson, ExportOptions::default())?;
+    /// ```
+    fn export_json(
+        self,
+        path: &str,
+        layout: JsonLayout,
+        options: ExportOptions,
+    ) -> Result<usize, Error>;
 }
 
 
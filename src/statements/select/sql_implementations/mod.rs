@@ -1,9 +1,11 @@
 use crate::{
-    Error,
+    Error, SQLImplementation,
     statements::select::{
         Column, SelectProps,
         sql_implementations::{
-            mutate_query::{filters, group_by, join_operations, order_by},
+            mutate_query::{
+                filters, group_by, join_operations, limit_offset, limit_offset_oracle, order_by,
+            },
             oracle::columns::get_column_names_oracle,
         },
     },
@@ -43,6 +45,53 @@ impl SelectProps {
             .map(|col| -> Result<String, Error> { col.to_query_string(self) })
             .collect::<Result<Vec<String>, Error>>()
     }
+
+    /// Renders the full SELECT query without executing it,
+    /// so it can be used inside of other statements like CREATE VIEW.
+    pub(crate) fn to_sql(&self) -> Result<String, Error> {
+        self.query_for(&self.query_columns()?)
+    }
+
+    /// The selected columns as they're written in the query, with `Column::ALL` expanded into the table's columns.
+    pub(crate) fn query_columns(&self) -> Result<Vec<String>, Error> {
+        Ok(match &self.connect {
+            SQLImplementation::Oracle(_) => self.oracle_column_name()?,
+            SQLImplementation::SQLite(conn_info) => self
+                .columns
+                .iter()
+                .map(|col| -> Result<String, Error> {
+                    match col {
+                        Column::ALL(all) => Ok(conn_info
+                            .table_info(&self.table)?
+                            .iter()
+                            .map(|col| format!("{}.{}", all, col))
+                            .collect::<Vec<String>>()
+                            .join(", ")),
+                        col => col.to_query_string(self),
+                    }
+                })
+                .collect::<Result<Vec<String>, Error>>()?,
+        })
+    }
+
+    /// Renders the SELECT query for the columns from [`query_columns`](SelectProps::query_columns).
+    pub(crate) fn query_for(&self, columns: &[String]) -> Result<String, Error> {
+        self.finish_query(format!("SELECT {} FROM {}", columns.join(", "), self.table))
+    }
+
+    /// Renders the query counting the rows the SELECT returns.
+    pub(crate) fn count_query(&self) -> Result<String, Error> {
+        self.finish_query(format!("SELECT COUNT(*) FROM {}", self.table))
+    }
+
+    // Adds the joins, filters, grouping, ordering and limit shared by the SELECT and its count
+    fn finish_query(&self, query: String) -> Result<String, Error> {
+        let query = shared_select_operations(self, query)?;
+        Ok(match &self.connect {
+            SQLImplementation::Oracle(_) => limit_offset_oracle(self, query),
+            SQLImplementation::SQLite(_) => limit_offset(self, query),
+        })
+    }
 }
 
 impl Column {
@@ -7,8 +7,7 @@ use crate::{
     statements::select::{
         SelectProps,
         sql_implementations::{
-            multithread::multithread_execution, mutate_query::limit_offset_oracle,
-            oracle::execution::oracle_handle_execution, shared_select_operations,
+            multithread::multithread_execution, oracle::execution::oracle_handle_execution,
         },
     },
 };
@@ -19,8 +18,7 @@ pub mod execution;
 pub(crate) fn oracle_build_select(
     select_props: SelectProps,
 ) -> Result<Vec<Vec<Box<SQLDataTypes>>>, Error> {
-    let table = &select_props.table;
-    let cols = select_props.oracle_column_name()?;
+    let cols = select_props.query_columns()?;
 
     let header = &cols
         .iter()
@@ -34,17 +32,8 @@ pub(crate) fn oracle_build_select(
             Box::new(col.to_sql_fmt())
         })
         .collect::<Vec<Box<SQLDataTypes>>>();
-    let columns = &cols.join(", ");
-    
-    let mut query = format!("SELECT {} FROM {}", &columns, &table,);
-
-    let mut count_sql = format!("SELECT COUNT(*) FROM {}", &table);
-
-    query = shared_select_operations(&select_props, query)?;
-    count_sql = shared_select_operations(&select_props, count_sql)?;
-
-    query = limit_offset_oracle(&select_props, query);
-    count_sql = limit_offset_oracle(&select_props, count_sql);
+    let query = select_props.query_for(&cols)?;
+    let count_sql = select_props.count_query()?;
 
     let conn_info = extract_connection(&select_props.connect)?;
     let conn: oracle::Connection = oracle::Connection::connect(
@@ -67,8 +56,7 @@ pub(crate) fn oracle_build_select(
 pub(crate) fn oracle_build_single_thread_select(
     select_props: SelectProps,
 ) -> Result<Vec<Vec<Box<SQLDataTypes>>>, Error> {
-    let table = &select_props.table;
-    let cols = select_props.oracle_column_name()?;
+    let cols = select_props.query_columns()?;
 
     let header = &cols
         .iter()
@@ -82,12 +70,7 @@ pub(crate) fn oracle_build_single_thread_select(
             Box::new(col.to_sql_fmt())
         })
         .collect::<Vec<Box<SQLDataTypes>>>();
-    let columns = &cols.join(", ");
-
-    let mut query = format!("SELECT {} FROM {}", &columns, &table,);
-
-    query = shared_select_operations(&select_props, query)?;
-    query = limit_offset_oracle(&select_props, query);
+    let query = select_props.query_for(&cols)?;
 
     let conn_info = extract_connection(&select_props.connect)?;
     let conn: oracle::Connection = oracle::Connection::connect(
@@ -1,6 +1,13 @@
 use std::sync::Arc;
 
-use crate::{Error, SQLImplementation, data_types::SQLDataTypes, statements::select::SelectProps};
+use crate::{
+    Error, SQLImplementation,
+    data_types::{
+        SQLDataTypes,
+        sqlite::{declared_types, sqlite_cell},
+    },
+    statements::select::SelectProps,
+};
 
 pub fn sqlite_handle_execution(
     select_props: Arc<SelectProps>,
@@ -13,12 +20,13 @@ pub fn sqlite_handle_execution(
     };
     let conn = conn_info.initialize_connection()?;
     let mut stmt = conn.prepare(&sql)?;
+    let declared_types = declared_types(&stmt);
     let mut rows = stmt.query([])?;
     let mut res = Vec::new();
     while let Some(row) = rows.next()? {
         let mut p = Vec::new();
         for idx in 0..column_size {
-            p.push(Box::new(row.get::<usize, SQLDataTypes>(idx).unwrap()))
+            p.push(Box::new(sqlite_cell(row, idx, &declared_types).unwrap()))
         }
         p.remove(0); // Removes the row number
         res.push(p)
@@ -1,11 +1,13 @@
 use crate::{
     Error, SQLImplementation,
-    data_types::{SQLDataTypes, ToSQLData},
+    data_types::{
+        SQLDataTypes, ToSQLData,
+        sqlite::{declared_types, sqlite_cell},
+    },
     statements::select::{
         SelectProps,
         sql_implementations::{
-            multithread::multithread_execution, mutate_query::limit_offset,
-            shared_select_operations, sqlite::execution::sqlite_handle_execution,
+            multithread::multithread_execution, sqlite::execution::sqlite_handle_execution,
         },
     },
 };
@@ -22,29 +24,7 @@ pub(crate) fn build_select_sqlite(
 
     let conn = conn_info.initialize_connection()?;
 
-    let table = &select_props.table;
-    let cols = &select_props
-        .columns
-        .iter()
-        .map(|col| -> Result<String, Error> {
-            let col = match col {
-                crate::statements::select::Column::Name(name) => {
-                    format!("{}.{}", name.table, name.name)
-                }
-                crate::statements::select::Column::Function(function) => format!("{}", function),
-                crate::statements::select::Column::Varchar(varchar) => format!("'{}'", varchar),
-                crate::statements::select::Column::ALL(all) => {
-                    let columns = conn_info.table_info(&table)?;
-                    columns
-                        .iter()
-                        .map(|col| format!("{}.{}", all, col))
-                        .collect::<Vec<String>>()
-                        .join(", ")
-                }
-            };
-            Ok(col)
-        })
-        .collect::<Result<Vec<String>, Error>>()?;
+    let cols = &select_props.query_columns()?;
 
     let columns = &cols.join(", ");
     let head = &columns.split(",").collect::<Vec<&str>>();
@@ -60,16 +40,8 @@ pub(crate) fn build_select_sqlite(
             Box::new(col.to_sql_fmt())
         })
         .collect::<Vec<Box<SQLDataTypes>>>();
-    let columns = &cols.join(", ");
-
-    let mut query = format!("SELECT {} FROM {}", &columns, &table,);
-    let mut count_sql = format!("SELECT COUNT(*) FROM {}", &table);
-
-    query = shared_select_operations(&select_props, query)?;
-    count_sql = shared_select_operations(&select_props, count_sql)?;
-
-    query = limit_offset(&select_props, query);
-    count_sql = limit_offset(&select_props, count_sql);
+    let query = select_props.query_for(cols)?;
+    let count_sql = select_props.count_query()?;
 
     let mut count: Option<usize> = None;
     let mut stmt = conn.prepare(&count_sql)?;
@@ -94,41 +66,16 @@ pub(crate) fn build_select_sqlite_single_thread(
         SQLImplementation::SQLite(connect) => connect,
     };
 
-    let table = &select_props.table;
-    let cols = &select_props
-        .columns
-        .iter()
-        .map(|col| -> Result<String, Error> {
-            let col = match col {
-                crate::statements::select::Column::Name(name) => {
-                    format!("{}.{}", name.table, name.name)
-                }
-                crate::statements::select::Column::Function(function) => format!("{}", function),
-                crate::statements::select::Column::Varchar(varchar) => format!("'{}'", varchar),
-                crate::statements::select::Column::ALL(all) => {
-                    let columns = conn_info.table_info(&table)?;
-                    columns
-                        .iter()
-                        .map(|col| format!("{}.{}", all, col))
-                        .collect::<Vec<String>>()
-                        .join(", ")
-                }
-            };
-            Ok(col)
-        })
-        .collect::<Result<Vec<String>, Error>>()?;
+    let cols = &select_props.query_columns()?;
 
     let columns = &cols.join(", ");
 
     let conn = conn_info.initialize_connection()?;
 
-    let mut query = format!("SELECT {} FROM {}", &columns, &table,);
-
-    query = shared_select_operations(&select_props, query)?;
-
-    query = limit_offset(&select_props, query);
+    let query = select_props.query_for(cols)?;
 
     let mut stmt = conn.prepare(&query)?;
+    let declared_types = declared_types(&stmt);
     let mut rows = stmt.query([])?;
     let mut res = Vec::new();
     let header = columns.split(",").collect::<Vec<&str>>();
@@ -136,7 +83,7 @@ pub(crate) fn build_select_sqlite_single_thread(
         let p = header
             .iter()
             .enumerate()
-            .map(|(idx, _)| Box::new(row.get::<usize, SQLDataTypes>(idx).unwrap()))
+            .map(|(idx, _)| Box::new(sqlite_cell(row, idx, &declared_types).unwrap()))
             .collect::<Vec<Box<SQLDataTypes>>>();
         res.push(p)
     }
@@ -0,0 +1,17 @@
+use crate::{
+    Error, SQLImplementation,
+    statements::truncate::sql_implementations::{
+        oracle::oracle_build_truncate, sqlite::sqlite_build_truncate,
+    },
+};
+
+use super::{TruncateBuilder, TruncateProps};
+
+impl TruncateBuilder for TruncateProps {
+    fn build(self) -> Result<(), Error> {
+        match self.connect {
+            SQLImplementation::Oracle(_) => oracle_build_truncate(self),
+            SQLImplementation::SQLite(_) => sqlite_build_truncate(self),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use crate::{Error, SQLImplementation};
+
+pub mod implement;
+pub mod sql_implementations;
+
+#[derive(Debug)]
+pub struct TruncateProps {
+    pub connect: SQLImplementation,
+    pub table: String,
+}
+
+pub trait TruncateBuilder {
+    /// Builds the TRUNCATE query.
+    fn build(self) -> Result<(), Error>;
+}
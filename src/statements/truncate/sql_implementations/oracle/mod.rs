@@ -0,0 +1,16 @@
+use crate::{Error, SQLImplementation, statements::truncate::TruncateProps};
+
+pub(crate) fn oracle_build_truncate(props: TruncateProps) -> Result<(), Error> {
+    let conn_info = match &props.connect {
+        SQLImplementation::Oracle(oracle_connect) => oracle_connect,
+        SQLImplementation::SQLite(_) => return Err(Error::SQLVariationError),
+    };
+
+    let conn: oracle::Connection = oracle::Connection::connect(
+        &conn_info.username,
+        &conn_info.password,
+        &conn_info.connection_string,
+    )?;
+    conn.execute(&format!("TRUNCATE TABLE {}", props.table), &[])?;
+    Ok(())
+}
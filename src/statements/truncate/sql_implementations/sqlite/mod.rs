@@ -0,0 +1,26 @@
+use crate::{Error, SQLImplementation, statements::truncate::TruncateProps};
+
+pub(crate) fn sqlite_build_truncate(props: TruncateProps) -> Result<(), Error> {
+    let conn_info = match &props.connect {
+        SQLImplementation::Oracle(_) => return Err(Error::SQLVariationError),
+        SQLImplementation::SQLite(connect) => connect,
+    };
+    let mut conn = conn_info.initialize_connection()?;
+
+    // SQLite doesn't have TRUNCATE, so everything is deleted and the AUTOINCREMENT counter is reset
+    let tx = conn.transaction()?;
+    tx.execute(&format!("DELETE FROM {}", props.table), ())?;
+    let has_sequence: bool = tx.query_row(
+        "SELECT EXISTS (SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'sqlite_sequence')",
+        (),
+        |row| row.get(0),
+    )?;
+    if has_sequence {
+        tx.execute(
+            "DELETE FROM sqlite_sequence WHERE name = ?1",
+            [&props.table],
+        )?;
+    }
+    tx.commit()?;
+    Ok(())
+}
@@ -1,13 +1,13 @@
 use crate::{
     Error, SQLImplementation,
-    data_types::ToSQLData,
+    data_types::{SQLDataTypes, ToSQLData},
     statements::update::sql_implementations::{
-        oracle::{batch_update_oracle, oracle_build_update},
-        sqlite::{batch_update_sqlite, sqlite_build_update},
+        oracle::{batch_update_oracle, oracle_build_update, oracle_build_update_returning},
+        sqlite::{batch_update_sqlite, sqlite_build_update, sqlite_build_update_returning},
     },
 };
 
-use super::{SetMatch, UpdateBuilder, UpdateProps};
+use super::{SetMatch, UpdateBuilder, UpdateProps, UpdateReturning};
 
 impl UpdateBuilder for UpdateProps {
     fn set<T: ToSQLData>(mut self, column: &str, new_value: T) -> Self {
@@ -47,6 +47,23 @@ impl UpdateBuilder for UpdateProps {
             SQLImplementation::SQLite(_) => sqlite_build_update(self),
         }
     }
+
+    fn returning(self, columns: &[&str]) -> UpdateReturning {
+        UpdateReturning {
+            update_props: self,
+            columns: columns.iter().map(|col| col.to_string()).collect(),
+        }
+    }
+}
+
+impl UpdateReturning {
+    /// Builds the query and returns a row of the returning columns for each updated row.
+    pub fn build(self) -> Result<Vec<Vec<SQLDataTypes>>, Error> {
+        match self.update_props.connect {
+            SQLImplementation::Oracle(_) => oracle_build_update_returning(self),
+            SQLImplementation::SQLite(_) => sqlite_build_update_returning(self),
+        }
+    }
 }
 
 pub fn batch_update(updates: Vec<UpdateProps>) -> Result<(), Error> {
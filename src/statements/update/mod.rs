@@ -14,6 +14,13 @@ pub struct UpdateProps {
     pub clause: Option<String>,
 }
 
+/// An update that returns columns of the updated rows, created with [`returning`](UpdateBuilder::returning).
+#[derive(Debug)]
+pub struct UpdateReturning {
+    pub update_props: UpdateProps,
+    pub columns: Vec<String>,
+}
+
 #[derive(Debug)]
 pub struct SetMatch {
     pub column: String,
@@ -41,4 +48,14 @@ pub trait UpdateBuilder {
 
     /// Builds the query and returns the number of row updated.
     fn build_return_count(self) -> Result<usize, Error>;
+
+    /// Returns these columns for every updated row when the [`UpdateReturning`] is built.
+    /// ```no_run
+    /// let updated = conn.update("orders")
+    ///     .set("status", "shipped")
+    ///     .where_in(&order_date, dates)
+    ///     .returning(&["order_id", "status"])
+    ///     .build()?;
+    /// ```
+    fn returning(self, columns: &[&str]) -> UpdateReturning;
 }
@@ -1,11 +1,50 @@
-use crate::{Error, SQLImplementation, data_types::SQLDataTypes, statements::update::UpdateProps};
+use crate::{
+    Error, SQLImplementation,
+    data_types::SQLDataTypes,
+    sql_implementations::{
+        oracle::{fmt_returning_into, returned_rows, returning_binds},
+        utils::fmt_oracle_interval,
+    },
+    statements::update::{UpdateProps, UpdateReturning},
+};
 
 pub(crate) fn oracle_build_update(update_set: UpdateProps) -> Result<usize, Error> {
-    let conn_info = match update_set.connect {
+    let conn_info = match &update_set.connect {
         SQLImplementation::Oracle(oracle_connect) => oracle_connect,
         SQLImplementation::SQLite(_) => return Err(Error::SQLVariationError),
     };
 
+    let query = oracle_update_sql(&update_set)?;
+    let count_sql = match &update_set.clause {
+        Some(filters) => format!(
+            "SELECT COUNT(*) FROM {} WHERE {}",
+            &update_set.table, &filters
+        ),
+        None => format!("SELECT COUNT(*) FROM {}", &update_set.table),
+    };
+
+    let conn: oracle::Connection = oracle::Connection::connect(
+        &conn_info.username,
+        &conn_info.password,
+        &conn_info.connection_string,
+    )
+    .unwrap();
+
+    let mut count: usize = 0;
+    let mut stmt = conn.statement(&count_sql).build()?;
+    let stmt_query = stmt.query(&[])?;
+    for v in stmt_query {
+        let p = v?;
+        count = p.get::<usize, usize>(0)?;
+    }
+
+    conn.execute(&query, &[])?;
+    conn.commit()?;
+
+    Ok(count)
+}
+
+pub(crate) fn oracle_update_sql(update_set: &UpdateProps) -> Result<String, Error> {
     let set_match_len = &update_set.set_match.len();
     let set_vec = update_set.set_match.iter().enumerate().map(|(idx, set_match)| {
         let fmt_data_types: String;
@@ -20,7 +59,14 @@ pub(crate) fn oracle_build_update(update_set: UpdateProps) -> Result<usize, Erro
                 SQLDataTypes::Varchar(val) => format!("'{}'", val),
                 SQLDataTypes::Number(val) => format!("{}", val),
                 SQLDataTypes::Float(val) => format!("{}", val),
-                SQLDataTypes::Date(val) => format!("to_date(to_char(to_timestamp('{}', 'YYYY-MM-DD HH24:MI:SS.FF3'), 'YYYY-MM-DD HH24:MI:SS'), 'YYYY-MM-DD HH24:MI:SS')", val),
+                SQLDataTypes::Decimal(val) => val.to_plain_string(),
+                SQLDataTypes::Date(val) => format!("to_date('{}', 'YYYY-MM-DD')", val),
+                SQLDataTypes::Time(val) => format!("'{}'", val),
+                SQLDataTypes::Timestamp(val) => format!("to_date(to_char(to_timestamp('{}', 'YYYY-MM-DD HH24:MI:SS.FF3'), 'YYYY-MM-DD HH24:MI:SS'), 'YYYY-MM-DD HH24:MI:SS')", val),
+                SQLDataTypes::TimestampTz(val) => format!("to_timestamp_tz('{}', 'YYYY-MM-DD HH24:MI:SS.FF9 TZH:TZM')", val.format("%Y-%m-%d %H:%M:%S%.9f %:z")),
+                SQLDataTypes::Interval(val) => fmt_oracle_interval(val),
+                SQLDataTypes::Bool(val) => format!("{}", i64::from(*val)),
+                SQLDataTypes::Json(val) => format!("'{}'", val.to_string().replace('\'', "''")),
                 SQLDataTypes::NULL => format!("''"),
             };
         }
@@ -33,40 +79,41 @@ pub(crate) fn oracle_build_update(update_set: UpdateProps) -> Result<usize, Erro
 
     let set = set_vec.join(" ");
 
-    let count_sql: String;
-    let query = match update_set.clause {
-        Some(filters) => {
-            count_sql = format!(
-                "SELECT COUNT(*) FROM {} WHERE {}",
-                &update_set.table, &filters
-            );
-            format!("UPDATE {} {} WHERE {}", &update_set.table, set, filters)
-        }
-        None => {
-            count_sql = format!("SELECT COUNT(*) FROM {}", &update_set.table);
-            format!("UPDATE {} {}", &update_set.table, set)
-        }
+    Ok(match &update_set.clause {
+        Some(filters) => format!("UPDATE {} {} WHERE {}", &update_set.table, set, filters),
+        None => format!("UPDATE {} {}", &update_set.table, set),
+    })
+}
+
+pub(crate) fn oracle_build_update_returning(
+    update_returning: UpdateReturning,
+) -> Result<Vec<Vec<SQLDataTypes>>, Error> {
+    let update_set = &update_returning.update_props;
+    let conn_info = match &update_set.connect {
+        SQLImplementation::Oracle(oracle_connect) => oracle_connect,
+        SQLImplementation::SQLite(_) => return Err(Error::SQLVariationError),
     };
 
+    let query = format!(
+        "{}{}",
+        oracle_update_sql(update_set)?,
+        fmt_returning_into(&update_returning.columns, 0)
+    );
+    let binds = returning_binds(conn_info, &update_set.table, &update_returning.columns)?;
+    let params = binds
+        .iter()
+        .map(|bind| bind as &dyn oracle::sql_type::ToSql)
+        .collect::<Vec<&dyn oracle::sql_type::ToSql>>();
+
     let conn: oracle::Connection = oracle::Connection::connect(
         &conn_info.username,
         &conn_info.password,
         &conn_info.connection_string,
-    )
-    .unwrap();
-
-    let mut count: usize = 0;
-    let mut stmt = conn.statement(&count_sql).build()?;
-    let stmt_query = stmt.query(&[])?;
-    for v in stmt_query {
-        let p = v?;
-        count = p.get::<usize, usize>(0)?;
-    }
-
-    conn.execute(&query, &[])?;
+    )?;
+    let stmt = conn.execute(&query, &params)?;
+    let res = returned_rows(&stmt, 0, update_returning.columns.len())?;
     conn.commit()?;
-
-    Ok(count)
+    Ok(res)
 }
 
 pub fn batch_update_oracle(updates: Vec<UpdateProps>) -> Result<(), Error> {
@@ -85,7 +132,14 @@ pub fn batch_update_oracle(updates: Vec<UpdateProps>) -> Result<(), Error> {
                 SQLDataTypes::Varchar(val) => format!("'{}'", val),
                 SQLDataTypes::Number(val) => format!("{}", val),
                 SQLDataTypes::Float(val) => format!("{}", val),
-                SQLDataTypes::Date(val) => format!("to_date(to_char(to_timestamp('{}', 'YYYY-MM-DD HH24:MI:SS.FF3'), 'YYYY-MM-DD HH24:MI:SS'), 'YYYY-MM-DD HH24:MI:SS')", val),
+                SQLDataTypes::Decimal(val) => val.to_plain_string(),
+                SQLDataTypes::Date(val) => format!("to_date('{}', 'YYYY-MM-DD')", val),
+                SQLDataTypes::Time(val) => format!("'{}'", val),
+                SQLDataTypes::Timestamp(val) => format!("to_date(to_char(to_timestamp('{}', 'YYYY-MM-DD HH24:MI:SS.FF3'), 'YYYY-MM-DD HH24:MI:SS'), 'YYYY-MM-DD HH24:MI:SS')", val),
+                SQLDataTypes::TimestampTz(val) => format!("to_timestamp_tz('{}', 'YYYY-MM-DD HH24:MI:SS.FF9 TZH:TZM')", val.format("%Y-%m-%d %H:%M:%S%.9f %:z")),
+                SQLDataTypes::Interval(val) => fmt_oracle_interval(val),
+                SQLDataTypes::Bool(val) => format!("{}", i64::from(*val)),
+                SQLDataTypes::Json(val) => format!("'{}'", val.to_string().replace('\'', "''")),
                 SQLDataTypes::NULL => format!("''"),
             };
 
@@ -1,4 +1,9 @@
-use crate::{Error, SQLImplementation, data_types::SQLDataTypes, statements::update::UpdateProps};
+use crate::{
+    Error, SQLImplementation,
+    data_types::SQLDataTypes,
+    sql_implementations::sqlite::returning_rows,
+    statements::update::{UpdateProps, UpdateReturning},
+};
 
 pub(crate) fn sqlite_build_update(update_set: UpdateProps) -> Result<usize, Error> {
     let conn_info = match &update_set.connect {
@@ -6,35 +11,13 @@ pub(crate) fn sqlite_build_update(update_set: UpdateProps) -> Result<usize, Erro
         SQLImplementation::SQLite(connect) => connect,
     };
 
-    let set_match_len = &update_set.set_match.len();
-    let set = update_set.set_match.iter().enumerate().map(|(idx, set_match)| {
-        let fmt_data_types = match &set_match.value {
-            SQLDataTypes::Varchar(val) => format!("'{}'", val),
-            SQLDataTypes::Number(val) => format!("{}", val),
-            SQLDataTypes::Float(val) => format!("{}", val),
-            SQLDataTypes::Date(val) => format!("to_date(to_char(to_timestamp('{}', 'YYYY-MM-DD HH24:MI:SS.FF3'), 'YYYY-MM-DD HH24:MI:SS'), 'YYYY-MM-DD HH24:MI:SS')", val),
-            SQLDataTypes::NULL => format!("''"),
-        };
-
-        if set_match_len == &1 { format!("SET {} = {}", set_match.column, fmt_data_types) }
-        else if idx == 0 { format!("SET {} = {},", set_match.column, fmt_data_types) }
-        else if &idx == &(set_match_len - 1) { format!("{} = {}", set_match.column, fmt_data_types) }
-        else { format!("{} = {},", set_match.column, fmt_data_types) }
-    }).collect::<Vec<String>>().join(" ");
-
-    let count_sql: String;
-    let query = match update_set.clause {
-        Some(filters) => {
-            count_sql = format!(
-                "SELECT COUNT(*) FROM {} WHERE {}",
-                &update_set.table, &filters
-            );
-            format!("UPDATE {} {} WHERE {}", &update_set.table, set, filters)
-        }
-        None => {
-            count_sql = format!("SELECT COUNT(*) FROM {}", &update_set.table);
-            format!("UPDATE {} {}", &update_set.table, set)
-        }
+    let query = sqlite_update_sql(&update_set);
+    let count_sql = match &update_set.clause {
+        Some(filters) => format!(
+            "SELECT COUNT(*) FROM {} WHERE {}",
+            &update_set.table, &filters
+        ),
+        None => format!("SELECT COUNT(*) FROM {}", &update_set.table),
     };
 
     let conn = conn_info.initialize_connection()?;
@@ -46,13 +29,72 @@ pub(crate) fn sqlite_build_update(update_set: UpdateProps) -> Result<usize, Erro
         res.push(row.get(0).unwrap())
     }
 
-    if res.len() == 0 {
+    if res.is_empty() {
         return Err(Error::CountError);
     }
 
     Ok(res[0])
 }
 
+pub(crate) fn sqlite_update_sql(update_set: &UpdateProps) -> String {
+    let set_match_len = &update_set.set_match.len();
+    let set = update_set
+        .set_match
+        .iter()
+        .enumerate()
+        .map(|(idx, set_match)| {
+            let fmt_data_types = match &set_match.value {
+                SQLDataTypes::Varchar(val) => format!("'{}'", val),
+                SQLDataTypes::Number(val) => format!("{}", val),
+                SQLDataTypes::Float(val) => format!("{}", val),
+                SQLDataTypes::Decimal(val) => format!("'{}'", val.to_plain_string()),
+                SQLDataTypes::Date(val) => format!("'{}'", val),
+                SQLDataTypes::Time(val) => format!("'{}'", val),
+                SQLDataTypes::Timestamp(val) => format!("'{}'", val),
+                SQLDataTypes::TimestampTz(val) => format!("'{}'", val.to_rfc3339()),
+                SQLDataTypes::Interval(val) => format!("'{}'", val),
+                SQLDataTypes::Bool(val) => format!("{}", i64::from(*val)),
+                SQLDataTypes::Json(val) => format!("'{}'", val.to_string().replace('\'', "''")),
+                SQLDataTypes::NULL => format!("''"),
+            };
+
+            if set_match_len == &1 {
+                format!("SET {} = {}", set_match.column, fmt_data_types)
+            } else if idx == 0 {
+                format!("SET {} = {},", set_match.column, fmt_data_types)
+            } else if &idx == &(set_match_len - 1) {
+                format!("{} = {}", set_match.column, fmt_data_types)
+            } else {
+                format!("{} = {},", set_match.column, fmt_data_types)
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ");
+
+    match &update_set.clause {
+        Some(filters) => format!("UPDATE {} {} WHERE {}", &update_set.table, set, filters),
+        None => format!("UPDATE {} {}", &update_set.table, set),
+    }
+}
+
+pub(crate) fn sqlite_build_update_returning(
+    update_returning: UpdateReturning,
+) -> Result<Vec<Vec<SQLDataTypes>>, Error> {
+    let conn_info = match &update_returning.update_props.connect {
+        SQLImplementation::Oracle(_) => return Err(Error::SQLVariationError),
+        SQLImplementation::SQLite(connect) => connect,
+    };
+
+    let query = format!(
+        "{} RETURNING {}",
+        sqlite_update_sql(&update_returning.update_props),
+        update_returning.columns.join(", ")
+    );
+    let conn = conn_info.initialize_connection()?;
+    let mut stmt = conn.prepare(&query)?;
+    returning_rows(&mut stmt, [])
+}
+
 pub fn batch_update_sqlite(updates: Vec<UpdateProps>) -> Result<(), Error> {
     let connect = &updates[0].connect;
     // let table = &updates[0].query_type.table;
@@ -61,27 +103,51 @@ pub fn batch_update_sqlite(updates: Vec<UpdateProps>) -> Result<(), Error> {
         SQLImplementation::SQLite(connect) => connect,
     };
 
-    let sql = updates.iter().map(|update| {
-        let set_match_len = &update.set_match.len();
-        let set = update.set_match.iter().enumerate().map(|(idx, set_match)| {
-            let fmt_data_types = match &set_match.value {
-                SQLDataTypes::Varchar(val) => format!("'{}'", val),
-                SQLDataTypes::Number(val) => format!("{}", val),
-                SQLDataTypes::Float(val) => format!("{}", val),
-                SQLDataTypes::Date(val) => format!("to_date(to_char(to_timestamp('{}', 'YYYY-MM-DD HH24:MI:SS.FF3'), 'YYYY-MM-DD HH24:MI:SS'), 'YYYY-MM-DD HH24:MI:SS')", val),
-                SQLDataTypes::NULL => format!("''"),
-            };
+    let sql = updates
+        .iter()
+        .map(|update| {
+            let set_match_len = &update.set_match.len();
+            let set = update
+                .set_match
+                .iter()
+                .enumerate()
+                .map(|(idx, set_match)| {
+                    let fmt_data_types = match &set_match.value {
+                        SQLDataTypes::Varchar(val) => format!("'{}'", val),
+                        SQLDataTypes::Number(val) => format!("{}", val),
+                        SQLDataTypes::Float(val) => format!("{}", val),
+                        SQLDataTypes::Decimal(val) => format!("'{}'", val.to_plain_string()),
+                        SQLDataTypes::Date(val) => format!("'{}'", val),
+                        SQLDataTypes::Time(val) => format!("'{}'", val),
+                        SQLDataTypes::Timestamp(val) => format!("'{}'", val),
+                        SQLDataTypes::TimestampTz(val) => format!("'{}'", val.to_rfc3339()),
+                        SQLDataTypes::Interval(val) => format!("'{}'", val),
+                        SQLDataTypes::Bool(val) => format!("{}", i64::from(*val)),
+                        SQLDataTypes::Json(val) => {
+                            format!("'{}'", val.to_string().replace('\'', "''"))
+                        }
+                        SQLDataTypes::NULL => format!("''"),
+                    };
 
-            if set_match_len == &1 { format!("SET {} = {}", set_match.column, fmt_data_types) }
-            else if idx == 0 { format!("SET {} = {},", set_match.column, fmt_data_types) }
-            else if &idx == &(set_match_len - 1) { format!("{} = {}", set_match.column, fmt_data_types) }
-            else { format!("{} = {},", set_match.column, fmt_data_types) }
-        }).collect::<Vec<String>>().join(" ");
-        match &update.clause {
-            Some(clause) => format!("UPDATE {} {} WHERE {}", &update.table, set, clause),
-            None => format!("UPDATE {} {}", &update.table, set),
-        }
-    }).collect::<Vec<String>>().join("; ");
+                    if set_match_len == &1 {
+                        format!("SET {} = {}", set_match.column, fmt_data_types)
+                    } else if idx == 0 {
+                        format!("SET {} = {},", set_match.column, fmt_data_types)
+                    } else if &idx == &(set_match_len - 1) {
+                        format!("{} = {}", set_match.column, fmt_data_types)
+                    } else {
+                        format!("{} = {},", set_match.column, fmt_data_types)
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join(" ");
+            match &update.clause {
+                Some(clause) => format!("UPDATE {} {} WHERE {}", &update.table, set, clause),
+                None => format!("UPDATE {} {}", &update.table, set),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("; ");
 
     let query = format!("BEGIN; {sql}; COMMIT;");
 
@@ -0,0 +1,312 @@
+use indicatif::ProgressBar;
+
+use crate::{
+    Error, SQLImplementation,
+    data_types::SQLDataTypes,
+    schema::{ColumnSchema, TableSchema},
+    sql_implementations::{OracleConnect, SQLiteConnect},
+    statements::create::{CreateDataTypes, ModifyCreateTable},
+};
+
+pub mod oracle;
+pub mod sqlite;
+
+/// Options for [`copy_table`]. Defaults to every column, no filter, and chunks of 10,000 rows.
+#[derive(Debug, Clone)]
+pub struct CopyOptions {
+    /// Only copy these columns. Copies every column when empty.
+    pub columns: Vec<String>,
+    /// A WHERE clause (without the `WHERE`) applied to the source table, e.g. `region = 'West'`.
+    pub filter: Option<String>,
+    /// Number of rows read and written at a time.
+    pub chunk_size: usize,
+    /// Name of the table in the target database. Uses the source table's name when `None`.
+    pub target_table: Option<String>,
+    pub progress_bar: bool,
+}
+
+/// What [`copy_table`] did.
+#[derive(Debug, Clone)]
+pub struct CopyReport {
+    pub table: String,
+    /// Whether the target table was created or the rows were appended to an existing table.
+    pub created: bool,
+    pub rows_copied: usize,
+    pub chunks: usize,
+}
+
+impl Default for CopyOptions {
+    fn default() -> Self {
+        CopyOptions {
+            columns: Vec::new(),
+            filter: None,
+            chunk_size: 10_000,
+            target_table: None,
+            progress_bar: false,
+        }
+    }
+}
+
+impl CopyOptions {
+    pub fn new() -> Self {
+        CopyOptions::default()
+    }
+
+    /// Only copy these columns from the source table.
+    pub fn columns(mut self, columns: &[&str]) -> Self {
+        self.columns = columns.iter().map(|column| column.to_string()).collect();
+        self
+    }
+
+    /// Only copy the rows matching this WHERE clause, written in the source database's dialect.
+    pub fn filter(mut self, filter: &str) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    pub fn target_table(mut self, table: &str) -> Self {
+        self.target_table = Some(table.to_string());
+        self
+    }
+
+    /// Uses [indicatif](`indicatif::ProgressBar`) to show the rows copied in the terminal.
+    pub fn progress_bar(mut self) -> Self {
+        self.progress_bar = true;
+        self
+    }
+}
+
+/// Copies a table from one database to another, streaming the rows in chunks so the table never has to fit in memory.
+///
+/// If the target table doesn't exist, it's created from the source table's schema (translated to the target database),
+/// otherwise the rows are appended to it. Foreign keys aren't carried over since the tables they reference may not exist in the target.
+/// Each chunk is committed on its own, so a failed copy leaves the rows from the chunks before it.
+/// ```no_run
+/// let oracle = SQLImplementation::Oracle(OracleConnect::new(connection_string, username, password)?);
+/// let sqlite = SQLImplementation::SQLite(SQLiteConnect::from_path("analysis.db"));
+/// let options = CopyOptions::new()
+///     .columns(&["product_id", "city", "revenue"])
+///     .filter("revenue > 1000")
+///     .progress_bar();
+/// let report = copy_table(&oracle, &sqlite, "regional_sales", options)?;
+/// println!("{} rows copied into {}", report.rows_copied, report.table);
+/// ```
+pub fn copy_table(
+    from: &SQLImplementation,
+    to: &SQLImplementation,
+    table: &str,
+    options: CopyOptions,
+) -> Result<CopyReport, Error> {
+    let schema = from.describe_table(table)?;
+    let columns = copy_columns(&schema, &options.columns)?;
+    let header = columns
+        .iter()
+        .map(|column| column.name.to_string())
+        .collect::<Vec<String>>();
+
+    let target_table = match &options.target_table {
+        Some(target_table) => target_table.to_string(),
+        // Drops the Oracle owner from `owner.table`
+        None => table.rsplit('.').next().unwrap_or(table).to_string(),
+    };
+
+    let (target_schema, created) = match to.describe_table(&target_table) {
+        Ok(target_schema) => (target_schema, false),
+        Err(Error::TableDoesNotExist) => {
+            create_target(&schema, &columns, to, &target_table)?;
+            (to.describe_table(&target_table)?, true)
+        }
+        Err(err) => return Err(err),
+    };
+    // Binds follow the target's column types, which can differ from the source's when the table already existed
+    let target_columns = copy_columns(&target_schema, &header)?;
+
+    let mut query = format!("SELECT {} FROM {}", header.join(", "), table);
+    if let Some(filter) = &options.filter {
+        query = format!("{query} WHERE {filter}");
+    }
+
+    // Copying inside the same SQLite file can't hold a read and a write connection at once
+    if let (
+        SQLImplementation::SQLite(SQLiteConnect::Path(source)),
+        SQLImplementation::SQLite(SQLiteConnect::Path(target)),
+    ) = (from, to)
+        && source == target
+    {
+        let rows_copied = sqlite::copy_within(from, &target_table, &header, &query)?;
+        return Ok(CopyReport {
+            table: target_table,
+            created,
+            rows_copied,
+            chunks: 1,
+        });
+    }
+
+    let progress_bar = if options.progress_bar {
+        let count = match from {
+            SQLImplementation::Oracle(connect) => oracle::count_rows(connect, &query)?,
+            SQLImplementation::SQLite(connect) => sqlite::count_rows(connect, &query)?,
+        };
+        Some(ProgressBar::new(count as u64))
+    } else {
+        None
+    };
+
+    let mut writer = ChunkWriter::new(to, &target_table, &header, &target_columns)?;
+    let mut report = CopyReport {
+        table: target_table.to_string(),
+        created,
+        rows_copied: 0,
+        chunks: 0,
+    };
+    let write_chunk = |chunk: Vec<Vec<SQLDataTypes>>| -> Result<(), Error> {
+        let len = chunk.len();
+        writer.write(chunk)?;
+        report.rows_copied += len;
+        report.chunks += 1;
+        if let Some(progress_bar) = &progress_bar {
+            progress_bar.inc(len as u64);
+        }
+        Ok(())
+    };
+
+    match from {
+        SQLImplementation::Oracle(connect) => oracle::read_chunks(
+            connect,
+            &query,
+            header.len(),
+            options.chunk_size,
+            write_chunk,
+        )?,
+        SQLImplementation::SQLite(connect) => sqlite::read_chunks(
+            connect,
+            &query,
+            header.len(),
+            options.chunk_size,
+            write_chunk,
+        )?,
+    }
+
+    if let Some(progress_bar) = &progress_bar {
+        progress_bar.finish();
+    }
+
+    Ok(report)
+}
+
+fn copy_columns(schema: &TableSchema, columns: &[String]) -> Result<Vec<ColumnSchema>, Error> {
+    if columns.is_empty() {
+        return Ok(schema.columns.to_owned());
+    }
+    columns
+        .iter()
+        .map(|name| {
+            schema
+                .columns
+                .iter()
+                .find(|column| column.name.eq_ignore_ascii_case(name))
+                .cloned()
+                .ok_or(Error::ColumnDoesNotExist(name.to_string()))
+        })
+        .collect()
+}
+
+fn create_target(
+    schema: &TableSchema,
+    columns: &[ColumnSchema],
+    to: &SQLImplementation,
+    target_table: &str,
+) -> Result<(), Error> {
+    let mut subset = schema.to_owned();
+    subset.table = target_table.to_string();
+    subset.columns = columns.to_vec();
+    // A primary key is only kept if all of its columns are copied
+    if !schema.primary_key.iter().all(|pk| {
+        columns
+            .iter()
+            .any(|column| column.name.eq_ignore_ascii_case(pk))
+    }) {
+        subset.primary_key = Vec::new();
+    }
+    // Same for unique constraints
+    subset.indexes.retain(|index| {
+        index.columns.iter().all(|name| {
+            columns
+                .iter()
+                .any(|column| column.name.eq_ignore_ascii_case(name))
+        })
+    });
+    subset.foreign_keys = Vec::new();
+    subset.to_create_table(to.to_owned()).build()
+}
+
+// Keeps the target connection open between chunks
+pub(crate) enum ChunkWriter {
+    Oracle {
+        conn: ::oracle::Connection,
+        query: String,
+        temporal: Vec<usize>,
+    },
+    SQLite {
+        conn: rusqlite::Connection,
+        query: String,
+    },
+}
+
+impl ChunkWriter {
+    pub(crate) fn new(
+        to: &SQLImplementation,
+        table: &str,
+        header: &[String],
+        columns: &[ColumnSchema],
+    ) -> Result<Self, Error> {
+        match to {
+            SQLImplementation::Oracle(connect) => Ok(ChunkWriter::Oracle {
+                conn: oracle_connection(connect)?,
+                query: oracle::insert_query(table, header),
+                // Oracle won't take text in a DATE or TIMESTAMP column, so those cells are parsed before binding
+                temporal: columns
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, column)| {
+                        matches!(
+                            column.data_type,
+                            CreateDataTypes::DATE
+                                | CreateDataTypes::TIMESTAMP
+                                | CreateDataTypes::TIMESTAMPTZ
+                        )
+                    })
+                    .map(|(idx, _)| idx)
+                    .collect(),
+            }),
+            SQLImplementation::SQLite(connect) => Ok(ChunkWriter::SQLite {
+                conn: connect.initialize_connection()?,
+                query: sqlite::insert_query(table, header),
+            }),
+        }
+    }
+
+    pub(crate) fn write(&mut self, chunk: Vec<Vec<SQLDataTypes>>) -> Result<(), Error> {
+        match self {
+            ChunkWriter::Oracle {
+                conn,
+                query,
+                temporal,
+            } => oracle::write_chunk(conn, query, chunk, temporal),
+            ChunkWriter::SQLite { conn, query } => sqlite::write_chunk(conn, query, chunk),
+        }
+    }
+}
+
+fn oracle_connection(connect: &OracleConnect) -> Result<::oracle::Connection, Error> {
+    Ok(::oracle::Connection::connect(
+        &connect.username,
+        &connect.password,
+        &connect.connection_string,
+    )?)
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use indicatif::ProgressBar;
+
+use crate::{
+    Error,
+    data_types::SQLDataTypes,
+    sql_implementations::{OracleConnect, utils::get_dt_indices},
+    statements::insert::sql_implementations::oracle::{iter_grid::iter_grid, sql_fmt::insert_stmt},
+};
+
+pub(crate) fn read_chunks(
+    conn_info: &OracleConnect,
+    query: &str,
+    column_size: usize,
+    chunk_size: usize,
+    mut on_chunk: impl FnMut(Vec<Vec<SQLDataTypes>>) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let conn = oracle::Connection::connect(
+        &conn_info.username,
+        &conn_info.password,
+        &conn_info.connection_string,
+    )?;
+    let mut stmt = conn
+        .statement(query)
+        .fetch_array_size(chunk_size.min(u32::MAX as usize) as u32)
+        .build()?;
+
+    let mut chunk = Vec::with_capacity(chunk_size);
+    for row in stmt.query(&[])? {
+        let row = row?;
+        let cells = (0..column_size)
+            .map(|idx| row.get::<usize, SQLDataTypes>(idx))
+            .collect::<Result<Vec<SQLDataTypes>, oracle::Error>>()?;
+        chunk.push(cells);
+        if chunk.len() == chunk_size {
+            on_chunk(std::mem::replace(
+                &mut chunk,
+                Vec::with_capacity(chunk_size),
+            ))?;
+        }
+    }
+    if !chunk.is_empty() {
+        on_chunk(chunk)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn count_rows(conn_info: &OracleConnect, query: &str) -> Result<usize, Error> {
+    let conn = oracle::Connection::connect(
+        &conn_info.username,
+        &conn_info.password,
+        &conn_info.connection_string,
+    )?;
+    let count = conn.query_row_as::<u64>(&format!("SELECT COUNT(*) FROM ({query})"), &[])?;
+    Ok(count as usize)
+}
+
+pub(crate) fn insert_query(table: &str, header: &[String]) -> String {
+    insert_stmt(header.len(), &table.to_string(), &header.join(", "))
+}
+
+pub(crate) fn write_chunk(
+    conn: &oracle::Connection,
+    query: &str,
+    mut chunk: Vec<Vec<SQLDataTypes>>,
+    temporal: &[usize],
+) -> Result<(), Error> {
+    chunk.iter_mut().for_each(|row| {
+        temporal.iter().for_each(|idx| {
+            row[*idx].format_data_types();
+        })
+    });
+    let datatype_indices = get_dt_indices(&chunk);
+    let mut batch = conn.batch(query, chunk.len()).build()?;
+    iter_grid(
+        &mut batch,
+        chunk,
+        Arc::new(ProgressBar::hidden()),
+        datatype_indices,
+        false,
+    )?;
+    conn.commit()?;
+    Ok(())
+}
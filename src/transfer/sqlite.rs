@@ -0,0 +1,93 @@
+use crate::{
+    Error, SQLImplementation,
+    data_types::{
+        SQLDataTypes,
+        sqlite::{declared_types, sqlite_cell},
+    },
+    sql_implementations::SQLiteConnect,
+};
+
+pub(crate) fn read_chunks(
+    conn_info: &SQLiteConnect,
+    query: &str,
+    column_size: usize,
+    chunk_size: usize,
+    mut on_chunk: impl FnMut(Vec<Vec<SQLDataTypes>>) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let conn = conn_info.initialize_connection()?;
+    let mut stmt = conn.prepare(query)?;
+    let declared_types = declared_types(&stmt);
+    let mut rows = stmt.query([])?;
+
+    let mut chunk = Vec::with_capacity(chunk_size);
+    while let Some(row) = rows.next()? {
+        let cells = (0..column_size)
+            .map(|idx| sqlite_cell(row, idx, &declared_types))
+            .collect::<Result<Vec<SQLDataTypes>, rusqlite::Error>>()?;
+        chunk.push(cells);
+        if chunk.len() == chunk_size {
+            on_chunk(std::mem::replace(
+                &mut chunk,
+                Vec::with_capacity(chunk_size),
+            ))?;
+        }
+    }
+    if !chunk.is_empty() {
+        on_chunk(chunk)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn count_rows(conn_info: &SQLiteConnect, query: &str) -> Result<usize, Error> {
+    let conn = conn_info.initialize_connection()?;
+    let count = conn.query_row(&format!("SELECT COUNT(*) FROM ({query})"), [], |row| {
+        row.get::<usize, i64>(0)
+    })?;
+    Ok(count as usize)
+}
+
+pub(crate) fn insert_query(table: &str, header: &[String]) -> String {
+    let values = (1..=header.len())
+        .map(|idx| format!("?{idx}"))
+        .collect::<Vec<String>>();
+    format!(
+        "INSERT INTO {} ({}) VALUES ({})",
+        table,
+        header.join(", "),
+        values.join(", ")
+    )
+}
+
+pub(crate) fn write_chunk(
+    conn: &mut rusqlite::Connection,
+    query: &str,
+    chunk: Vec<Vec<SQLDataTypes>>,
+) -> Result<(), Error> {
+    let transaction = conn.transaction()?;
+    {
+        let mut stmt = transaction.prepare_cached(query)?;
+        for row in &chunk {
+            stmt.execute(rusqlite::params_from_iter(row.iter()))?;
+        }
+    }
+    transaction.commit()?;
+    Ok(())
+}
+
+// Both tables are in the same file, so it's a single INSERT ... SELECT
+pub(crate) fn copy_within(
+    connect: &SQLImplementation,
+    table: &str,
+    header: &[String],
+    query: &str,
+) -> Result<usize, Error> {
+    let conn = match connect {
+        SQLImplementation::Oracle(_) => return Err(Error::SQLVariationError),
+        SQLImplementation::SQLite(connect) => connect.initialize_connection()?,
+    };
+    let rows = conn.execute(
+        &format!("INSERT INTO {} ({}) {}", table, header.join(", "), query),
+        [],
+    )?;
+    Ok(rows)
+}